@@ -25,4 +25,44 @@ pub enum ErrorCode {
     NoFill,
     #[msg("SignedMsgOrderDoesNotExist")]
     SignedMsgOrderDoesNotExist,
+    #[msg("DeltaToleranceBreached")]
+    DeltaToleranceBreached,
+    #[msg("TakerAccountNotFound")]
+    TakerAccountNotFound,
+    #[msg("FillSizeBelowMinimum")]
+    FillSizeBelowMinimum,
+    #[msg("PriceTypeNotSupported")]
+    PriceTypeNotSupported,
+    #[msg("OracleConfidenceTooWide")]
+    OracleConfidenceTooWide,
+    #[msg("OracleTooStale")]
+    OracleTooStale,
+    #[msg("Expired")]
+    Expired,
+    #[msg("OracleDeviationTooLarge")]
+    OracleDeviationTooLarge,
+    #[msg("InsufficientEdge")]
+    InsufficientEdge,
+    #[msg("FillOrKillNotFilled")]
+    FillOrKillNotFilled,
+    #[msg("ReduceOnlyIncreasesPosition")]
+    ReduceOnlyIncreasesPosition,
+    #[msg("InvalidAuthority")]
+    InvalidAuthority,
+    #[msg("MakerConfigMarketMismatch")]
+    MakerConfigMarketMismatch,
+    #[msg("Paused")]
+    Paused,
+    #[msg("WindowNotionalLimitBreached")]
+    WindowNotionalLimitBreached,
+    #[msg("WindowLossLimitBreached")]
+    WindowLossLimitBreached,
+    #[msg("ExtraAccountsNotFound")]
+    ExtraAccountsNotFound,
+    #[msg("InvalidOracleDeviationParams")]
+    InvalidOracleDeviationParams,
+    #[msg("MarketIndexNotPausable")]
+    MarketIndexNotPausable,
+    #[msg("InvalidWindowDuration")]
+    InvalidWindowDuration,
 }
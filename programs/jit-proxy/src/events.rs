@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{MarketType, PositionDirection};
+
+#[event]
+pub struct JitFillEvent {
+    pub taker: Pubkey,
+    pub maker: Pubkey,
+    pub market_type: MarketType,
+    pub market_index: u16,
+    pub taker_direction: PositionDirection,
+    pub taker_price: u64,
+    pub maker_price: u64,
+    pub oracle_price: i64,
+    pub base_asset_amount_filled: u64,
+    pub slot: u64,
+}
+
+#[event]
+pub struct JitNoFillEvent {
+    pub taker: Pubkey,
+    pub maker: Pubkey,
+    pub market_type: MarketType,
+    pub market_index: u16,
+    pub taker_direction: PositionDirection,
+    pub taker_price: u64,
+    pub maker_price: u64,
+    pub oracle_price: i64,
+    /// none for spot markets
+    pub vamm_bid_price: Option<u64>,
+    pub vamm_ask_price: Option<u64>,
+    pub slot: u64,
+}
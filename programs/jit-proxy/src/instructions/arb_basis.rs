@@ -0,0 +1,411 @@
+use anchor_lang::prelude::*;
+use drift::controller::position::PositionDirection;
+use drift::cpi::accounts::PlaceAndTake;
+use drift::error::DriftResult;
+use drift::instructions::optional_accounts::{load_maps, AccountMaps};
+use drift::math::casting::Cast;
+use drift::math::constants::{BASE_PRECISION, BASE_PRECISION_I128};
+use drift::program::Drift;
+use drift::state::order_params::{OrderParams, OrderParamsBitFlag, PostOnlyParam};
+use std::collections::BTreeSet;
+
+use drift::math::orders::{find_bids_and_asks_from_users, standardize_base_asset_amount};
+use drift::math::safe_math::SafeMath;
+use drift::state::spot_market::SpotMarket;
+use drift::state::state::State;
+use drift::state::user::{MarketType, OrderTriggerCondition, OrderType, User, UserStats};
+use drift::state::user_map::load_user_maps;
+
+use super::arb_spot::{find_spot_bids_and_asks_from_users, get_signed_token_amount};
+use super::maker_control::check_maker_control;
+use super::risk_ledger::calculate_notional;
+use crate::error::ErrorCode;
+use crate::state::{MakerControl, RiskLedger};
+
+pub fn arb_basis<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ArbBasis<'info>>,
+    perp_market_index: u16,
+    spot_market_index: u16,
+    params: ArbBasisParams,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let slot = clock.slot;
+    let now = clock.unix_timestamp;
+
+    check_maker_control(
+        &ctx.accounts.maker_control,
+        MarketType::Perp,
+        perp_market_index,
+    )?;
+    check_maker_control(
+        &ctx.accounts.maker_control,
+        MarketType::Spot,
+        spot_market_index,
+    )?;
+
+    ctx.accounts.risk_ledger.update_window(now)?;
+    ctx.accounts.risk_ledger.check_limits()?;
+
+    let taker = ctx.accounts.user.load()?;
+
+    let (perp_base_init, perp_quote_init) = taker
+        .get_perp_position(perp_market_index)
+        .map_or((0, 0), |p| (p.base_asset_amount, p.quote_asset_amount));
+
+    // snapshot the positions so the token amounts before and after can be computed with the
+    // same cumulative interest
+    let spot_position_init = taker.get_spot_position(spot_market_index).ok().copied();
+    let quote_position_init = *taker.get_quote_spot_position();
+
+    let remaining_accounts_iter = &mut ctx.remaining_accounts.iter().peekable();
+    let AccountMaps {
+        perp_market_map,
+        mut oracle_map,
+        spot_market_map,
+    } = load_maps(
+        remaining_accounts_iter,
+        &BTreeSet::new(),
+        &BTreeSet::new(),
+        slot,
+        None,
+    )?;
+
+    let (makers, _) = load_user_maps(remaining_accounts_iter, true)?;
+
+    let perp_market = perp_market_map.get_ref(&perp_market_index)?;
+    let perp_oracle_price_data = oracle_map.get_price_data(&perp_market.oracle_id())?;
+
+    let (perp_bids, perp_asks) =
+        find_bids_and_asks_from_users(&perp_market, perp_oracle_price_data, &makers, slot, now)?;
+    let perp_oracle_price = perp_oracle_price_data.price;
+
+    let spot_market = spot_market_map.get_ref(&spot_market_index)?;
+    let spot_oracle_price_data = oracle_map.get_price_data(&spot_market.oracle_id())?;
+
+    let (spot_bids, spot_asks) = find_spot_bids_and_asks_from_users(
+        &spot_market,
+        spot_oracle_price_data,
+        &makers,
+        slot,
+        now,
+    )?;
+    let spot_oracle_price = spot_oracle_price_data.price;
+
+    let spot_precision = spot_market.get_precision();
+
+    // sell perp and buy spot when the perp bid is above the spot ask
+    let short_perp = match (perp_bids.first(), spot_asks.first()) {
+        (Some(perp_bid), Some(spot_ask)) => Some((
+            perp_bid
+                .price
+                .cast::<i64>()?
+                .safe_sub(spot_ask.price.cast()?)?,
+            perp_bid,
+            spot_ask,
+        )),
+        _ => None,
+    };
+
+    // buy perp and sell spot when the spot bid is above the perp ask
+    let long_perp = match (perp_asks.first(), spot_bids.first()) {
+        (Some(perp_ask), Some(spot_bid)) => Some((
+            spot_bid
+                .price
+                .cast::<i64>()?
+                .safe_sub(perp_ask.price.cast()?)?,
+            perp_ask,
+            spot_bid,
+        )),
+        _ => None,
+    };
+
+    let (perp_direction, spread, perp_level, spot_level) = match (short_perp, long_perp) {
+        (Some(short_perp), Some(long_perp)) if long_perp.0 > short_perp.0 => (
+            PositionDirection::Long,
+            long_perp.0,
+            long_perp.1,
+            long_perp.2,
+        ),
+        (Some(short_perp), _) => (
+            PositionDirection::Short,
+            short_perp.0,
+            short_perp.1,
+            short_perp.2,
+        ),
+        (None, Some(long_perp)) => (
+            PositionDirection::Long,
+            long_perp.0,
+            long_perp.1,
+            long_perp.2,
+        ),
+        (None, None) => return Err(ErrorCode::NoArbOpportunity.into()),
+    };
+
+    msg!("spread {} min spread {}", spread, params.min_spread);
+
+    if spread < params.min_spread.cast()? {
+        return Err(ErrorCode::NoArbOpportunity.into());
+    }
+
+    // size the perp leg in base precision, then convert it to the spot market's token precision
+    let spot_level_base_asset_amount = spot_level
+        .base_asset_amount
+        .cast::<u128>()?
+        .safe_mul(BASE_PRECISION)?
+        .safe_div(spot_precision.cast()?)?
+        .cast::<u64>()?;
+
+    let perp_base_asset_amount = standardize_base_asset_amount(
+        perp_level
+            .base_asset_amount
+            .min(spot_level_base_asset_amount)
+            .min(params.max_base_asset_amount),
+        perp_market.amm.order_step_size,
+    )?;
+
+    let spot_base_asset_amount = standardize_base_asset_amount(
+        perp_base_asset_amount
+            .cast::<u128>()?
+            .safe_mul(spot_precision.cast()?)?
+            .safe_div(BASE_PRECISION)?
+            .cast()?,
+        spot_market.order_step_size,
+    )?;
+
+    if perp_base_asset_amount < perp_market.amm.min_order_size
+        || spot_base_asset_amount < spot_market.min_order_size
+    {
+        msg!(
+            "perp base asset amount {} spot base asset amount {} below min order size",
+            perp_base_asset_amount,
+            spot_base_asset_amount
+        );
+        return Err(ErrorCode::NoArbOpportunity.into());
+    }
+
+    let get_order_params = |market_type: MarketType,
+                            market_index: u16,
+                            direction: PositionDirection,
+                            base_asset_amount: u64,
+                            price: u64|
+     -> OrderParams {
+        OrderParams {
+            order_type: OrderType::Limit,
+            market_type,
+            direction,
+            user_order_id: 0,
+            base_asset_amount,
+            price,
+            market_index,
+            reduce_only: false,
+            post_only: PostOnlyParam::None,
+            bit_flags: OrderParamsBitFlag::ImmediateOrCancel as u8,
+            max_ts: None,
+            trigger_price: None,
+            trigger_condition: OrderTriggerCondition::Above,
+            oracle_price_offset: None,
+            auction_duration: None,
+            auction_start_price: None,
+            auction_end_price: None,
+        }
+    };
+
+    let order_params = vec![
+        get_order_params(
+            MarketType::Perp,
+            perp_market_index,
+            perp_direction,
+            perp_base_asset_amount,
+            perp_level.price,
+        ),
+        get_order_params(
+            MarketType::Spot,
+            spot_market_index,
+            perp_direction.opposite(),
+            spot_base_asset_amount,
+            spot_level.price,
+        ),
+    ];
+
+    drop(taker);
+    drop(perp_market);
+    drop(spot_market);
+
+    place_and_take(&ctx, order_params)?;
+
+    let taker = ctx.accounts.user.load()?;
+    let spot_market = spot_market_map.get_ref(&spot_market_index)?;
+    let quote_spot_market = spot_market_map.get_quote_spot_market()?;
+
+    let (perp_base_end, perp_quote_end) = taker
+        .get_perp_position(perp_market_index)
+        .map_or((0, 0), |p| (p.base_asset_amount, p.quote_asset_amount));
+
+    let spot_base_init = get_signed_token_amount(spot_position_init, &spot_market)?;
+    let spot_base_end = get_signed_token_amount(
+        taker.get_spot_position(spot_market_index).ok().copied(),
+        &spot_market,
+    )?;
+
+    if perp_base_end == perp_base_init || spot_base_end == spot_base_init {
+        msg!(
+            "perp_base_end {} perp_base_init {} spot_base_end {} spot_base_init {}",
+            perp_base_end,
+            perp_base_init,
+            spot_base_end,
+            spot_base_init
+        );
+        return Err(ErrorCode::NoFill.into());
+    }
+
+    let delta_init = calculate_net_delta(perp_base_init, spot_base_init, &spot_market)?;
+    let delta_end = calculate_net_delta(perp_base_end, spot_base_end, &spot_market)?;
+
+    if delta_end.safe_sub(delta_init)?.unsigned_abs() > params.delta_tolerance.cast()? {
+        msg!(
+            "delta_end {} delta_init {} delta tolerance {}",
+            delta_end,
+            delta_init,
+            params.delta_tolerance
+        );
+        return Err(ErrorCode::DeltaToleranceBreached.into());
+    }
+
+    let quote_init = quote_position_init.get_signed_token_amount(&quote_spot_market)?;
+    let quote_end = taker
+        .get_quote_spot_position()
+        .get_signed_token_amount(&quote_spot_market)?;
+
+    let perp_quote_change = perp_quote_end.safe_sub(perp_quote_init)?;
+    let spot_quote_change = quote_end.safe_sub(quote_init)?;
+    let pnl = perp_quote_change
+        .cast::<i128>()?
+        .safe_add(spot_quote_change)?;
+
+    msg!(
+        "perp quote change {} spot quote change {} pnl {} min profit {}",
+        perp_quote_change,
+        spot_quote_change,
+        pnl,
+        params.min_profit_quote
+    );
+
+    if pnl < params.min_profit_quote.cast()? {
+        return Err(ErrorCode::UnprofitableArb.into());
+    }
+
+    let notional = calculate_notional(
+        perp_base_end
+            .safe_sub(perp_base_init)?
+            .unsigned_abs()
+            .cast()?,
+        perp_oracle_price,
+        BASE_PRECISION,
+    )?
+    .safe_add(calculate_notional(
+        spot_base_end.safe_sub(spot_base_init)?.unsigned_abs(),
+        spot_oracle_price,
+        spot_precision.cast()?,
+    )?)?;
+
+    ctx.accounts.risk_ledger.record(notional, pnl.cast()?)?;
+    ctx.accounts.risk_ledger.check_limits()?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ArbBasis<'info> {
+    pub state: Box<Account<'info, State>>,
+    #[account(mut)]
+    pub user: AccountLoader<'info, User>,
+    #[account(mut)]
+    pub user_stats: AccountLoader<'info, UserStats>,
+    pub authority: Signer<'info>,
+    pub drift_program: Program<'info, Drift>,
+    #[account(
+        seeds = [b"maker_control", user.key().as_ref()],
+        bump = maker_control.bump,
+    )]
+    pub maker_control: Option<Account<'info, MakerControl>>,
+    #[account(
+        mut,
+        seeds = [b"risk_ledger", user.key().as_ref()],
+        bump = risk_ledger.bump,
+    )]
+    pub risk_ledger: Account<'info, RiskLedger>,
+}
+
+#[derive(Debug, Clone, Copy, Default, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
+pub struct ArbBasisParams {
+    /// min difference between the bid and ask prices of the two venues
+    pub min_spread: u64,
+    /// max perp base asset amount to trade
+    pub max_base_asset_amount: u64,
+    /// max change in perp base plus spot token balance, in base precision
+    pub delta_tolerance: u64,
+    /// min combined change in perp quote and spot quote token balance
+    pub min_profit_quote: i64,
+}
+
+/// Returns perp base plus spot token balance in base precision
+fn calculate_net_delta(
+    perp_base_asset_amount: i64,
+    spot_token_amount: i128,
+    spot_market: &SpotMarket,
+) -> DriftResult<i128> {
+    spot_token_amount
+        .safe_mul(BASE_PRECISION_I128)?
+        .safe_div(spot_market.get_precision().cast()?)?
+        .safe_add(perp_base_asset_amount.cast()?)
+}
+
+fn place_and_take<'info>(
+    ctx: &Context<'_, '_, '_, 'info, ArbBasis<'info>>,
+    orders_params: Vec<OrderParams>,
+) -> Result<()> {
+    for order_params in orders_params {
+        let drift_program = ctx.accounts.drift_program.to_account_info().clone();
+        let cpi_accounts = PlaceAndTake {
+            state: ctx.accounts.state.to_account_info().clone(),
+            user: ctx.accounts.user.to_account_info().clone(),
+            user_stats: ctx.accounts.user_stats.to_account_info().clone(),
+            authority: ctx.accounts.authority.to_account_info().clone(),
+        };
+
+        let cpi_context = CpiContext::new(drift_program, cpi_accounts)
+            .with_remaining_accounts(ctx.remaining_accounts.into());
+
+        if order_params.market_type == MarketType::Perp {
+            drift::cpi::place_and_take_perp_order(cpi_context, order_params, None)?;
+        } else {
+            drift::cpi::place_and_take_spot_order(cpi_context, order_params, None, None)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use drift::math::constants::BASE_PRECISION_I64;
+    use drift::state::spot_market::SpotMarket;
+
+    #[test]
+    pub fn calculate_net_delta() {
+        let spot_market = SpotMarket {
+            decimals: 6,
+            ..SpotMarket::default()
+        };
+
+        // short 1 perp, long 1 spot
+        let net_delta =
+            super::calculate_net_delta(-BASE_PRECISION_I64, 1_000_000, &spot_market).unwrap();
+        assert_eq!(net_delta, 0);
+
+        // long 1 perp, long 0.5 spot
+        let net_delta =
+            super::calculate_net_delta(BASE_PRECISION_I64, 500_000, &spot_market).unwrap();
+        assert_eq!(net_delta, 1_500_000_000);
+    }
+}
@@ -1,33 +1,48 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::set_return_data;
 use drift::controller::position::PositionDirection;
 use drift::cpi::accounts::PlaceAndTake;
 use drift::error::DriftResult;
 use drift::instructions::optional_accounts::{load_maps, AccountMaps};
+use drift::math::amm::calculate_base_asset_amount_to_trade_to_price;
 use drift::math::casting::Cast;
 use drift::math::constants::{BASE_PRECISION, MARGIN_PRECISION_U128, QUOTE_PRECISION};
-use drift::math::margin::MarginRequirementType;
+use drift::math::margin::{
+    calculate_margin_requirement_and_total_collateral_and_liability_info, MarginRequirementType,
+};
 use drift::program::Drift;
 use drift::state::order_params::{OrderParams, OrderParamsBitFlag, PostOnlyParam};
 use std::collections::BTreeSet;
 use std::ops::Deref;
 
-use drift::math::orders::find_bids_and_asks_from_users;
+use drift::math::orders::{find_bids_and_asks_from_users, Level};
 use drift::math::safe_math::SafeMath;
+use drift::state::margin_calculation::MarginContext;
 use drift::state::oracle::OraclePriceData;
+use drift::state::perp_market::AMM;
 use drift::state::state::State;
 use drift::state::user::{MarketType, OrderTriggerCondition, OrderType, User, UserStats};
 use drift::state::user_map::load_user_maps;
 
+use super::maker_control::check_maker_control;
+use super::risk_ledger::calculate_notional;
 use crate::error::ErrorCode;
+use crate::state::{ArbSizing, MakerControl, RiskLedger};
 
 pub fn arb_perp<'c: 'info, 'info>(
     ctx: Context<'_, '_, 'c, 'info, ArbPerp<'info>>,
     market_index: u16,
+    params: ArbPerpParams,
 ) -> Result<()> {
     let clock = Clock::get()?;
     let slot = clock.slot;
     let now = clock.unix_timestamp;
 
+    check_maker_control(&ctx.accounts.maker_control, MarketType::Perp, market_index)?;
+
+    ctx.accounts.risk_ledger.update_window(now)?;
+    ctx.accounts.risk_ledger.check_limits()?;
+
     let taker = ctx.accounts.user.load()?;
 
     let (base_init, quote_init) = taker
@@ -47,26 +62,77 @@ pub fn arb_perp<'c: 'info, 'info>(
         None,
     )?;
 
-    let quote_asset_token_amount = taker
-        .get_quote_spot_position()
-        .get_token_amount(spot_market_map.get_quote_spot_market()?.deref())?;
+    let free_collateral = match params.sizing {
+        // assumes all free collateral in quote asset token
+        ArbSizing::QuoteAsset => taker
+            .get_quote_spot_position()
+            .get_token_amount(spot_market_map.get_quote_spot_market()?.deref())?,
+        ArbSizing::FreeCollateral => {
+            calculate_margin_requirement_and_total_collateral_and_liability_info(
+                &taker,
+                &perp_market_map,
+                &spot_market_map,
+                &mut oracle_map,
+                MarginContext::standard(MarginRequirementType::Initial),
+            )?
+            .get_free_collateral()?
+        }
+    };
 
     let (makers, _) = load_user_maps(remaining_accounts_iter, true)?;
 
     let perp_market = perp_market_map.get_ref(&market_index)?;
     let oracle_price_data = oracle_map.get_price_data(&perp_market.oracle_id())?;
 
-    let (bids, asks) =
+    let (mut bids, mut asks) =
         find_bids_and_asks_from_users(&perp_market, oracle_price_data, &makers, slot, now)?;
 
-    let best_bid = bids.first().ok_or(ErrorCode::NoBestBid)?;
-    let best_ask = asks.first().ok_or(ErrorCode::NoBestAsk)?;
+    // maker orders crossing the vamm can be filled against it, so include it as a level
+    let reserve_price = perp_market.amm.reserve_price()?;
+    let (amm_bid_price, amm_ask_price) = perp_market.amm.bid_ask_price(reserve_price)?;
+
+    let amm_ask = match bids.first() {
+        Some(best_bid) if best_bid.price > amm_ask_price => calculate_amm_level(
+            &perp_market.amm,
+            amm_ask_price,
+            best_bid.price,
+            PositionDirection::Long,
+        )?,
+        _ => None,
+    };
 
-    if best_bid.price < best_ask.price {
-        return Err(ErrorCode::NoArbOpportunity.into());
+    let amm_bid = match asks.first() {
+        Some(best_ask) if best_ask.price < amm_bid_price => calculate_amm_level(
+            &perp_market.amm,
+            amm_bid_price,
+            best_ask.price,
+            PositionDirection::Short,
+        )?,
+        _ => None,
+    };
+
+    let amm_ask_included = amm_ask.is_some();
+    if let Some(amm_ask) = amm_ask {
+        let index = asks.partition_point(|level| level.price <= amm_ask.price);
+        asks.insert(index, amm_ask);
+    }
+
+    let amm_bid_included = amm_bid.is_some();
+    if let Some(amm_bid) = amm_bid {
+        let index = bids.partition_point(|level| level.price >= amm_bid.price);
+        bids.insert(index, amm_bid);
+    }
+
+    if bids.is_empty() {
+        return Err(ErrorCode::NoBestBid.into());
+    }
+
+    if asks.is_empty() {
+        return Err(ErrorCode::NoBestAsk.into());
     }
 
-    let base_asset_amount = best_bid.base_asset_amount.min(best_ask.base_asset_amount);
+    let (base_asset_amount, _, _) =
+        find_crossed_levels(&bids, &asks, u64::MAX).ok_or(ErrorCode::NoArbOpportunity)?;
 
     let (intermediate_base, start_direction) = if base_init >= 0 {
         let intermediate_base = base_init.safe_sub(base_asset_amount.cast()?)?;
@@ -82,16 +148,32 @@ pub fn arb_perp<'c: 'info, 'info>(
         taker.is_high_leverage_mode(MarginRequirementType::Initial),
     )?;
 
-    // assumes all free collateral in quote asset token
-    let max_base_asset_amount = calculate_max_base_asset_amount(
-        quote_asset_token_amount,
-        init_margin_ratio,
-        oracle_price_data,
-    )?;
+    let max_base_asset_amount =
+        calculate_max_base_asset_amount(free_collateral, init_margin_ratio, oracle_price_data)?;
 
     let base_asset_amount = base_asset_amount
         .min(max_base_asset_amount.cast()?)
-        .max(perp_market.amm.min_order_size);
+        .min(params.max_base_asset_amount.unwrap_or(u64::MAX));
+
+    if base_asset_amount < perp_market.amm.min_order_size {
+        msg!(
+            "base asset amount {} below min order size {}",
+            base_asset_amount,
+            perp_market.amm.min_order_size
+        );
+        return Err(ErrorCode::NoArbOpportunity.into());
+    }
+
+    // walk the book again with the final size to find the deepest levels that need to be crossed
+    let (_, bid_price, ask_price) =
+        find_crossed_levels(&bids, &asks, base_asset_amount).ok_or(ErrorCode::NoArbOpportunity)?;
+
+    let (long_price, short_price) = calculate_taker_prices(
+        bid_price,
+        ask_price,
+        amm_ask_included.then_some(amm_ask_price),
+        amm_bid_included.then_some(amm_bid_price),
+    );
 
     let get_order_params = |taker_direction: PositionDirection, taker_price: u64| -> OrderParams {
         OrderParams {
@@ -115,22 +197,35 @@ pub fn arb_perp<'c: 'info, 'info>(
         }
     };
 
-    let order_params = if start_direction == PositionDirection::Long {
-        vec![
-            get_order_params(PositionDirection::Long, best_ask.price),
-            get_order_params(PositionDirection::Short, best_bid.price),
-        ]
+    let (first_order_params, second_order_params) = if start_direction == PositionDirection::Long {
+        (
+            get_order_params(PositionDirection::Long, long_price),
+            get_order_params(PositionDirection::Short, short_price),
+        )
     } else {
-        vec![
-            get_order_params(PositionDirection::Short, best_bid.price),
-            get_order_params(PositionDirection::Long, best_ask.price),
-        ]
+        (
+            get_order_params(PositionDirection::Short, short_price),
+            get_order_params(PositionDirection::Long, long_price),
+        )
     };
 
     drop(taker);
     drop(perp_market);
 
-    place_and_take(&ctx, order_params)?;
+    place_and_take(&ctx, first_order_params)?;
+
+    // the second leg must bring the position back to base_init, so the change after the first
+    // leg is the amount executed on each leg
+    let base_asset_amount_filled = ctx
+        .accounts
+        .user
+        .load()?
+        .get_perp_position(market_index)
+        .map_or(0, |p| p.base_asset_amount)
+        .safe_sub(base_init)?
+        .unsigned_abs();
+
+    place_and_take(&ctx, second_order_params)?;
 
     let taker = ctx.accounts.user.load()?;
     let (base_end, quote_end) = taker
@@ -148,7 +243,29 @@ pub fn arb_perp<'c: 'info, 'info>(
         return Err(ErrorCode::NoArbOpportunity.into());
     }
 
-    msg!("pnl {}", quote_end - quote_init);
+    let pnl = quote_end.safe_sub(quote_init)?;
+    msg!("pnl {} min profit {}", pnl, params.min_profit_quote);
+
+    if pnl < params.min_profit_quote {
+        return Err(ErrorCode::UnprofitableArb.into());
+    }
+
+    // the position is unchanged, so the notional is both legs at the oracle price
+    let notional = calculate_notional(
+        base_asset_amount_filled.cast::<u128>()?.safe_mul(2)?,
+        oracle_price_data.price,
+        BASE_PRECISION,
+    )?;
+
+    ctx.accounts.risk_ledger.record(notional, pnl)?;
+    ctx.accounts.risk_ledger.check_limits()?;
+
+    let summary = ArbSummary {
+        base_asset_amount: base_asset_amount_filled,
+        quote_pnl: pnl,
+        position: base_end,
+    };
+    set_return_data(&summary.try_to_vec()?);
 
     Ok(())
 }
@@ -162,15 +279,147 @@ pub struct ArbPerp<'info> {
     pub user_stats: AccountLoader<'info, UserStats>,
     pub authority: Signer<'info>,
     pub drift_program: Program<'info, Drift>,
+    #[account(
+        seeds = [b"maker_control", user.key().as_ref()],
+        bump = maker_control.bump,
+    )]
+    pub maker_control: Option<Account<'info, MakerControl>>,
+    #[account(
+        mut,
+        seeds = [b"risk_ledger", user.key().as_ref()],
+        bump = risk_ledger.bump,
+    )]
+    pub risk_ledger: Account<'info, RiskLedger>,
+}
+
+#[derive(Debug, Clone, Copy, Default, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
+pub struct ArbPerpParams {
+    pub min_profit_quote: i64,
+    pub max_base_asset_amount: Option<u64>,
+    pub sizing: ArbSizing,
+}
+
+/// Set as return data by arb_perp
+#[derive(Debug, Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
+pub struct ArbSummary {
+    /// base asset amount executed on each leg
+    pub base_asset_amount: u64,
+    pub quote_pnl: i64,
+    /// perp base asset amount after the arb
+    pub position: i64,
+}
+
+/// Matches bids against asks from the top of the book until the levels stop crossing or
+/// max_base_asset_amount is reached.
+///
+/// Returns the crossed base asset amount and the deepest crossed bid and ask prices
+fn find_crossed_levels(
+    bids: &[Level],
+    asks: &[Level],
+    max_base_asset_amount: u64,
+) -> Option<(u64, u64, u64)> {
+    let mut bids_iter = bids.iter();
+    let mut asks_iter = asks.iter();
+
+    let mut bid = bids_iter.next()?;
+    let mut ask = asks_iter.next()?;
+    let mut bid_base_asset_amount = bid.base_asset_amount;
+    let mut ask_base_asset_amount = ask.base_asset_amount;
+
+    let mut base_asset_amount: u64 = 0;
+    let mut bid_price = bid.price;
+    let mut ask_price = ask.price;
+
+    while bid.price >= ask.price && base_asset_amount < max_base_asset_amount {
+        let fill_base_asset_amount = bid_base_asset_amount
+            .min(ask_base_asset_amount)
+            .min(max_base_asset_amount - base_asset_amount);
+
+        base_asset_amount += fill_base_asset_amount;
+        bid_base_asset_amount -= fill_base_asset_amount;
+        ask_base_asset_amount -= fill_base_asset_amount;
+        bid_price = bid.price;
+        ask_price = ask.price;
+
+        if bid_base_asset_amount == 0 {
+            match bids_iter.next() {
+                Some(next_bid) => {
+                    bid = next_bid;
+                    bid_base_asset_amount = bid.base_asset_amount;
+                }
+                None => break,
+            }
+        }
+
+        if ask_base_asset_amount == 0 {
+            match asks_iter.next() {
+                Some(next_ask) => {
+                    ask = next_ask;
+                    ask_base_asset_amount = ask.base_asset_amount;
+                }
+                None => break,
+            }
+        }
+    }
+
+    if base_asset_amount == 0 {
+        return None;
+    }
+
+    Some((base_asset_amount, bid_price, ask_price))
+}
+
+/// Returns the vamm as a level at price, sized by how much it can trade in direction before
+/// its price reaches limit_price
+fn calculate_amm_level(
+    amm: &AMM,
+    price: u64,
+    limit_price: u64,
+    direction: PositionDirection,
+) -> DriftResult<Option<Level>> {
+    let (base_asset_amount, amm_direction) =
+        calculate_base_asset_amount_to_trade_to_price(amm, limit_price, direction)?;
+
+    if amm_direction != direction || base_asset_amount == 0 {
+        return Ok(None);
+    }
+
+    Ok(Some(Level {
+        price,
+        base_asset_amount,
+    }))
+}
+
+/// Returns the long and short taker prices for the deepest crossed bid and ask prices.
+///
+/// The vamm price moves as it fills, so the leg trading against it must be allowed to cross up
+/// to the price of the other leg
+fn calculate_taker_prices(
+    bid_price: u64,
+    ask_price: u64,
+    amm_ask_price: Option<u64>,
+    amm_bid_price: Option<u64>,
+) -> (u64, u64) {
+    let long_price = match amm_ask_price {
+        Some(amm_ask_price) if ask_price >= amm_ask_price => bid_price,
+        _ => ask_price,
+    };
+
+    let short_price = match amm_bid_price {
+        Some(amm_bid_price) if bid_price <= amm_bid_price => ask_price,
+        _ => bid_price,
+    };
+
+    (long_price, short_price)
 }
 
 fn calculate_max_base_asset_amount(
-    quote_asset_token_amount: u128,
+    free_collateral: u128,
     init_margin_ratio: u32,
     oracle_price_data: &OraclePriceData,
 ) -> DriftResult<u128> {
-    quote_asset_token_amount
-        .saturating_sub((quote_asset_token_amount / 100).min(10 * QUOTE_PRECISION)) // room for error
+    free_collateral
+        .saturating_sub((free_collateral / 100).min(10 * QUOTE_PRECISION)) // room for error
         .safe_mul(MARGIN_PRECISION_U128)?
         .safe_div(init_margin_ratio.cast()?)?
         .safe_mul(BASE_PRECISION)?
@@ -179,30 +428,34 @@ fn calculate_max_base_asset_amount(
 
 fn place_and_take<'info>(
     ctx: &Context<'_, '_, '_, 'info, ArbPerp<'info>>,
-    orders_params: Vec<OrderParams>,
+    order_params: OrderParams,
 ) -> Result<()> {
-    for order_params in orders_params {
-        let drift_program = ctx.accounts.drift_program.to_account_info().clone();
-        let cpi_accounts = PlaceAndTake {
-            state: ctx.accounts.state.to_account_info().clone(),
-            user: ctx.accounts.user.to_account_info().clone(),
-            user_stats: ctx.accounts.user_stats.to_account_info().clone(),
-            authority: ctx.accounts.authority.to_account_info().clone(),
-        };
+    let drift_program = ctx.accounts.drift_program.to_account_info().clone();
+    let cpi_accounts = PlaceAndTake {
+        state: ctx.accounts.state.to_account_info().clone(),
+        user: ctx.accounts.user.to_account_info().clone(),
+        user_stats: ctx.accounts.user_stats.to_account_info().clone(),
+        authority: ctx.accounts.authority.to_account_info().clone(),
+    };
 
-        let cpi_context = CpiContext::new(drift_program, cpi_accounts)
-            .with_remaining_accounts(ctx.remaining_accounts.into());
+    let cpi_context = CpiContext::new(drift_program, cpi_accounts)
+        .with_remaining_accounts(ctx.remaining_accounts.into());
 
-        drift::cpi::place_and_take_perp_order(cpi_context, order_params, None)?;
-    }
+    drift::cpi::place_and_take_perp_order(cpi_context, order_params, None)?;
 
     Ok(())
 }
 
 #[cfg(test)]
 mod test {
-    use drift::math::constants::{MARGIN_PRECISION, PRICE_PRECISION_I64, QUOTE_PRECISION};
+    use drift::controller::position::PositionDirection;
+    use drift::math::constants::{
+        AMM_RESERVE_PRECISION, MARGIN_PRECISION, PEG_PRECISION, PRICE_PRECISION_I64,
+        PRICE_PRECISION_U64, QUOTE_PRECISION,
+    };
+    use drift::math::orders::Level;
     use drift::state::oracle::OraclePriceData;
+    use drift::state::perp_market::AMM;
 
     #[test]
     pub fn calculate_max_base_asset_amount() {
@@ -222,4 +475,138 @@ mod test {
 
         assert_eq!(max_base_asset_amount, 9900000000);
     }
+
+    #[test]
+    pub fn find_crossed_levels() {
+        let level = |price: u64, base_asset_amount: u64| Level {
+            price,
+            base_asset_amount,
+        };
+
+        // not crossed
+        let bids = vec![level(99, 10)];
+        let asks = vec![level(100, 10)];
+        assert_eq!(super::find_crossed_levels(&bids, &asks, u64::MAX), None);
+
+        // only top level crossed
+        let bids = vec![level(101, 10), level(99, 10)];
+        let asks = vec![level(100, 5), level(102, 10)];
+        assert_eq!(
+            super::find_crossed_levels(&bids, &asks, u64::MAX),
+            Some((5, 101, 100))
+        );
+
+        // multiple levels crossed on both sides
+        let bids = vec![level(105, 10), level(103, 10), level(100, 10)];
+        let asks = vec![level(99, 5), level(101, 10), level(102, 10), level(104, 10)];
+        assert_eq!(
+            super::find_crossed_levels(&bids, &asks, u64::MAX),
+            Some((20, 103, 102))
+        );
+
+        // capped by max base asset amount
+        assert_eq!(
+            super::find_crossed_levels(&bids, &asks, 8),
+            Some((8, 105, 101))
+        );
+
+        // book exhausted while still crossed
+        let bids = vec![level(105, 10)];
+        let asks = vec![level(99, 5), level(101, 10)];
+        assert_eq!(
+            super::find_crossed_levels(&bids, &asks, u64::MAX),
+            Some((10, 105, 101))
+        );
+    }
+
+    #[test]
+    pub fn calculate_amm_level() {
+        // reserve price of 100
+        let amm = AMM {
+            base_asset_reserve: 100 * AMM_RESERVE_PRECISION,
+            quote_asset_reserve: 100 * AMM_RESERVE_PRECISION,
+            sqrt_k: 100 * AMM_RESERVE_PRECISION,
+            peg_multiplier: 100 * PEG_PRECISION,
+            ..AMM::default()
+        };
+
+        // maker bid at 121 crosses the vamm ask, which can sell until its price reaches 121
+        let level = super::calculate_amm_level(
+            &amm,
+            100 * PRICE_PRECISION_U64,
+            121 * PRICE_PRECISION_U64,
+            PositionDirection::Long,
+        )
+        .unwrap();
+        assert_eq!(
+            level.map(|level| (level.price, level.base_asset_amount)),
+            Some((100 * PRICE_PRECISION_U64, 9090909091))
+        );
+
+        // maker ask at 81 crosses the vamm bid, which can buy until its price reaches 81
+        let level = super::calculate_amm_level(
+            &amm,
+            100 * PRICE_PRECISION_U64,
+            81 * PRICE_PRECISION_U64,
+            PositionDirection::Short,
+        )
+        .unwrap();
+        assert_eq!(
+            level.map(|level| (level.price, level.base_asset_amount)),
+            Some((100 * PRICE_PRECISION_U64, 11111111111))
+        );
+
+        // limit price on the wrong side of the vamm
+        let level = super::calculate_amm_level(
+            &amm,
+            100 * PRICE_PRECISION_U64,
+            81 * PRICE_PRECISION_U64,
+            PositionDirection::Long,
+        )
+        .unwrap();
+        assert!(level.is_none());
+
+        // limit price at the reserve price
+        let level = super::calculate_amm_level(
+            &amm,
+            100 * PRICE_PRECISION_U64,
+            100 * PRICE_PRECISION_U64,
+            PositionDirection::Long,
+        )
+        .unwrap();
+        assert!(level.is_none());
+    }
+
+    #[test]
+    pub fn calculate_taker_prices() {
+        // no vamm levels, long at the ask and short at the bid
+        assert_eq!(
+            super::calculate_taker_prices(105, 101, None, None),
+            (101, 105)
+        );
+
+        // maker bid crossing the vamm ask, the long leg can cross up to the maker bid
+        assert_eq!(
+            super::calculate_taker_prices(105, 101, Some(101), None),
+            (105, 105)
+        );
+
+        // vamm ask included but the deepest ask crossed is a maker ask below it
+        assert_eq!(
+            super::calculate_taker_prices(105, 100, Some(101), None),
+            (100, 105)
+        );
+
+        // maker ask crossing the vamm bid, the short leg can cross down to the maker ask
+        assert_eq!(
+            super::calculate_taker_prices(99, 95, None, Some(99)),
+            (95, 95)
+        );
+
+        // vamm bid included but the deepest bid crossed is a maker bid above it
+        assert_eq!(
+            super::calculate_taker_prices(100, 95, None, Some(99)),
+            (95, 100)
+        );
+    }
 }
@@ -0,0 +1,365 @@
+use anchor_lang::prelude::*;
+use drift::controller::position::PositionDirection;
+use drift::cpi::accounts::PlaceAndTake;
+use drift::error::DriftResult;
+use drift::instructions::optional_accounts::{load_maps, AccountMaps};
+use drift::math::casting::Cast;
+use drift::math::constants::QUOTE_PRECISION;
+use drift::math::orders::Level;
+use drift::program::Drift;
+use drift::state::order_params::{OrderParams, OrderParamsBitFlag, PostOnlyParam};
+use std::collections::BTreeSet;
+use std::ops::Deref;
+
+use drift::math::safe_math::SafeMath;
+use drift::state::oracle::OraclePriceData;
+use drift::state::spot_market::SpotMarket;
+use drift::state::state::State;
+use drift::state::user::{
+    MarketType, OrderStatus, OrderTriggerCondition, OrderType, SpotPosition, User, UserStats,
+};
+use drift::state::user_map::{load_user_maps, UserMap};
+
+use super::maker_control::check_maker_control;
+use super::risk_ledger::calculate_notional;
+use crate::error::ErrorCode;
+use crate::state::{MakerControl, RiskLedger};
+
+pub fn arb_spot<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ArbSpot<'info>>,
+    market_index: u16,
+    params: ArbSpotParams,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let slot = clock.slot;
+    let now = clock.unix_timestamp;
+
+    check_maker_control(&ctx.accounts.maker_control, MarketType::Spot, market_index)?;
+
+    ctx.accounts.risk_ledger.update_window(now)?;
+    ctx.accounts.risk_ledger.check_limits()?;
+
+    let taker = ctx.accounts.user.load()?;
+
+    // snapshot the positions so the token amounts before and after can be computed with the
+    // same cumulative interest
+    let base_position_init = taker.get_spot_position(market_index).ok().copied();
+    let quote_position_init = *taker.get_quote_spot_position();
+
+    let remaining_accounts_iter = &mut ctx.remaining_accounts.iter().peekable();
+    let AccountMaps {
+        perp_market_map: _,
+        mut oracle_map,
+        spot_market_map,
+    } = load_maps(
+        remaining_accounts_iter,
+        &BTreeSet::new(),
+        &BTreeSet::new(),
+        slot,
+        None,
+    )?;
+
+    let quote_asset_token_amount =
+        quote_position_init.get_token_amount(spot_market_map.get_quote_spot_market()?.deref())?;
+
+    let (makers, _) = load_user_maps(remaining_accounts_iter, true)?;
+
+    let spot_market = spot_market_map.get_ref(&market_index)?;
+    let oracle_price_data = oracle_map.get_price_data(&spot_market.oracle_id())?;
+
+    let (bids, asks) =
+        find_spot_bids_and_asks_from_users(&spot_market, oracle_price_data, &makers, slot, now)?;
+
+    let best_bid = bids.first().ok_or(ErrorCode::NoBestBid)?;
+    let best_ask = asks.first().ok_or(ErrorCode::NoBestAsk)?;
+
+    if best_bid.price < best_ask.price {
+        return Err(ErrorCode::NoArbOpportunity.into());
+    }
+
+    let base_asset_amount = best_bid.base_asset_amount.min(best_ask.base_asset_amount);
+
+    let base_init = get_signed_token_amount(base_position_init, &spot_market)?;
+
+    // sell existing token balance first, otherwise buy with quote first
+    let (start_direction, max_base_asset_amount) = if base_init > 0 {
+        (PositionDirection::Short, base_init.unsigned_abs())
+    } else {
+        let max_base_asset_amount = calculate_max_base_asset_amount(
+            quote_asset_token_amount,
+            spot_market.get_precision().cast()?,
+            oracle_price_data,
+        )?;
+        (PositionDirection::Long, max_base_asset_amount)
+    };
+
+    let base_asset_amount = base_asset_amount.min(max_base_asset_amount.cast()?);
+
+    if base_asset_amount < spot_market.min_order_size {
+        msg!(
+            "base asset amount {} below min order size {}",
+            base_asset_amount,
+            spot_market.min_order_size
+        );
+        return Err(ErrorCode::NoArbOpportunity.into());
+    }
+
+    let get_order_params = |taker_direction: PositionDirection, taker_price: u64| -> OrderParams {
+        OrderParams {
+            order_type: OrderType::Limit,
+            market_type: MarketType::Spot,
+            direction: taker_direction,
+            user_order_id: 0,
+            base_asset_amount,
+            price: taker_price,
+            market_index,
+            reduce_only: false,
+            post_only: PostOnlyParam::None,
+            bit_flags: OrderParamsBitFlag::ImmediateOrCancel as u8,
+            max_ts: None,
+            trigger_price: None,
+            trigger_condition: OrderTriggerCondition::Above,
+            oracle_price_offset: None,
+            auction_duration: None,
+            auction_start_price: None,
+            auction_end_price: None,
+        }
+    };
+
+    let (first_order_params, second_order_params) = if start_direction == PositionDirection::Long {
+        (
+            get_order_params(PositionDirection::Long, best_ask.price),
+            get_order_params(PositionDirection::Short, best_bid.price),
+        )
+    } else {
+        (
+            get_order_params(PositionDirection::Short, best_bid.price),
+            get_order_params(PositionDirection::Long, best_ask.price),
+        )
+    };
+
+    let oracle_price = oracle_price_data.price;
+    let base_precision: u128 = spot_market.get_precision().cast()?;
+
+    drop(taker);
+    drop(spot_market);
+
+    place_and_take(&ctx, first_order_params)?;
+
+    // measure the first leg from the token balance so the ledger records what was executed
+    let base_asset_amount_filled = {
+        let spot_market = spot_market_map.get_ref(&market_index)?;
+        let base_init = get_signed_token_amount(base_position_init, &spot_market)?;
+        get_signed_token_amount(
+            ctx.accounts
+                .user
+                .load()?
+                .get_spot_position(market_index)
+                .ok()
+                .copied(),
+            &spot_market,
+        )?
+        .safe_sub(base_init)?
+        .unsigned_abs()
+    };
+
+    place_and_take(&ctx, second_order_params)?;
+
+    let taker = ctx.accounts.user.load()?;
+    let spot_market = spot_market_map.get_ref(&market_index)?;
+    let quote_spot_market = spot_market_map.get_quote_spot_market()?;
+
+    let base_init = get_signed_token_amount(base_position_init, &spot_market)?;
+    let base_end = get_signed_token_amount(
+        taker.get_spot_position(market_index).ok().copied(),
+        &spot_market,
+    )?;
+    let quote_init = quote_position_init.get_signed_token_amount(&quote_spot_market)?;
+    let quote_end = taker
+        .get_quote_spot_position()
+        .get_signed_token_amount(&quote_spot_market)?;
+
+    // drift rounds token amounts when updating balances, so the base leg can be off by a few units
+    if base_end.safe_sub(base_init)?.unsigned_abs() > params.base_tolerance.cast()?
+        || quote_end <= quote_init
+    {
+        msg!(
+            "base_end {} base_init {} base tolerance {} quote_end {} quote_init {}",
+            base_end,
+            base_init,
+            params.base_tolerance,
+            quote_end,
+            quote_init
+        );
+        return Err(ErrorCode::NoArbOpportunity.into());
+    }
+
+    let pnl = quote_end.safe_sub(quote_init)?;
+    msg!("pnl {}", pnl);
+
+    // the balance is back within tolerance, so the notional is both legs at the oracle price
+    let notional = calculate_notional(
+        base_asset_amount_filled.safe_mul(2)?,
+        oracle_price,
+        base_precision,
+    )?;
+
+    ctx.accounts.risk_ledger.record(notional, pnl.cast()?)?;
+    ctx.accounts.risk_ledger.check_limits()?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ArbSpot<'info> {
+    pub state: Box<Account<'info, State>>,
+    #[account(mut)]
+    pub user: AccountLoader<'info, User>,
+    #[account(mut)]
+    pub user_stats: AccountLoader<'info, UserStats>,
+    pub authority: Signer<'info>,
+    pub drift_program: Program<'info, Drift>,
+    #[account(
+        seeds = [b"maker_control", user.key().as_ref()],
+        bump = maker_control.bump,
+    )]
+    pub maker_control: Option<Account<'info, MakerControl>>,
+    #[account(
+        mut,
+        seeds = [b"risk_ledger", user.key().as_ref()],
+        bump = risk_ledger.bump,
+    )]
+    pub risk_ledger: Account<'info, RiskLedger>,
+}
+
+#[derive(Debug, Clone, Copy, Default, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
+pub struct ArbSpotParams {
+    /// max change in the spot token balance, in the spot market's token precision
+    pub base_tolerance: u64,
+}
+
+pub(crate) fn find_spot_bids_and_asks_from_users(
+    spot_market: &SpotMarket,
+    oracle_price_data: &OraclePriceData,
+    users: &UserMap,
+    slot: u64,
+    now: i64,
+) -> Result<(Vec<Level>, Vec<Level>)> {
+    let mut bids: Vec<Level> = Vec::with_capacity(32);
+    let mut asks: Vec<Level> = Vec::with_capacity(32);
+
+    for (_, user) in users.0.iter() {
+        let user = user.load()?;
+
+        for order in user.orders.iter() {
+            if order.status != OrderStatus::Open
+                || order.market_type != MarketType::Spot
+                || order.market_index != spot_market.market_index
+            {
+                continue;
+            }
+
+            // if order has attached trigger or is not resting, skip
+            if !order.is_resting_limit_order(slot)? {
+                continue;
+            }
+
+            // expired orders are cancelled by drift rather than filled
+            if order.max_ts != 0 && order.max_ts < now {
+                continue;
+            }
+
+            let price = match order.get_limit_price(
+                Some(oracle_price_data.price),
+                None,
+                slot,
+                spot_market.order_tick_size,
+                false,
+                None,
+            )? {
+                Some(price) => price,
+                None => continue,
+            };
+
+            let level = Level {
+                price,
+                base_asset_amount: order.get_base_asset_amount_unfilled(None)?,
+            };
+
+            match order.direction {
+                PositionDirection::Long => bids.push(level),
+                PositionDirection::Short => asks.push(level),
+            }
+        }
+    }
+
+    bids.sort_by(|a, b| b.price.cmp(&a.price));
+    asks.sort_by(|a, b| a.price.cmp(&b.price));
+
+    Ok((bids, asks))
+}
+
+pub(crate) fn get_signed_token_amount(
+    spot_position: Option<SpotPosition>,
+    spot_market: &SpotMarket,
+) -> DriftResult<i128> {
+    spot_position.map_or(Ok(0), |spot_position| {
+        spot_position.get_signed_token_amount(spot_market)
+    })
+}
+
+fn calculate_max_base_asset_amount(
+    quote_asset_token_amount: u128,
+    spot_market_precision: u128,
+    oracle_price_data: &OraclePriceData,
+) -> DriftResult<u128> {
+    quote_asset_token_amount
+        .saturating_sub((quote_asset_token_amount / 100).min(10 * QUOTE_PRECISION)) // room for error
+        .safe_mul(spot_market_precision)?
+        .safe_div(oracle_price_data.price.cast()?)
+}
+
+fn place_and_take<'info>(
+    ctx: &Context<'_, '_, '_, 'info, ArbSpot<'info>>,
+    order_params: OrderParams,
+) -> Result<()> {
+    let drift_program = ctx.accounts.drift_program.to_account_info().clone();
+    let cpi_accounts = PlaceAndTake {
+        state: ctx.accounts.state.to_account_info().clone(),
+        user: ctx.accounts.user.to_account_info().clone(),
+        user_stats: ctx.accounts.user_stats.to_account_info().clone(),
+        authority: ctx.accounts.authority.to_account_info().clone(),
+    };
+
+    let cpi_context = CpiContext::new(drift_program, cpi_accounts)
+        .with_remaining_accounts(ctx.remaining_accounts.into());
+
+    drift::cpi::place_and_take_spot_order(cpi_context, order_params, None, None)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use drift::math::constants::{PRICE_PRECISION_I64, QUOTE_PRECISION};
+    use drift::state::oracle::OraclePriceData;
+
+    #[test]
+    pub fn calculate_max_base_asset_amount() {
+        let quote_asset_token_amount = 100 * QUOTE_PRECISION;
+        let spot_market_precision = 1_000_000_000;
+        let oracle_price_data = OraclePriceData {
+            price: 100 * PRICE_PRECISION_I64,
+            ..OraclePriceData::default()
+        };
+
+        let max_base_asset_amount = super::calculate_max_base_asset_amount(
+            quote_asset_token_amount,
+            spot_market_precision,
+            &oracle_price_data,
+        )
+        .unwrap();
+
+        assert_eq!(max_base_asset_amount, 990000000);
+    }
+}
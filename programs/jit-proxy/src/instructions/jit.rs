@@ -1,39 +1,64 @@
 use anchor_lang::prelude::Pubkey;
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::set_return_data;
 use drift::controller::position::PositionDirection;
 use drift::cpi::accounts::{PlaceAndMake, PlaceAndMakeSignedMsg};
 use drift::error::DriftResult;
 use drift::instructions::optional_accounts::{load_maps, AccountMaps};
 use drift::math::casting::Cast;
+use drift::math::constants::{BASE_PRECISION, PRICE_PRECISION, QUOTE_PRECISION};
+use drift::math::fees::determine_user_fee_tier;
 use drift::math::safe_math::SafeMath;
 use drift::program::Drift;
+use drift::state::oracle::OraclePriceData;
+use drift::state::oracle_map::OracleMap;
 use drift::state::order_params::{OrderParams, OrderParamsBitFlag};
 use drift::state::perp_market_map::PerpMarketMap;
 use drift::state::signed_msg_user::SignedMsgUserOrdersLoader;
 use drift::state::spot_market_map::SpotMarketMap;
-use drift::state::state::State;
+use drift::state::state::{FeeTier, State};
 use drift::state::user::Order;
 use drift::state::user::{MarketType as DriftMarketType, OrderTriggerCondition, OrderType};
 use drift::state::user::{User, UserStats};
 use std::collections::BTreeSet;
 
+use super::maker_control::check_maker_control;
+use super::risk_ledger::{get_base_and_quote_amounts, get_base_precision, record_fill};
 use crate::error::ErrorCode;
-use crate::state::PriceType;
+use crate::events::{JitFillEvent, JitNoFillEvent};
+use crate::state::{FillMode, MakerControl, PriceType, RiskLedger};
 use drift::state::order_params::PostOnlyParam;
 
+const BPS_PRECISION: i64 = 10_000;
+
 pub fn jit<'c: 'info, 'info>(
     ctx: Context<'_, '_, 'c, 'info, Jit<'info>>,
     params: JitParams,
+) -> Result<()> {
+    jit_inner(ctx.accounts, ctx.remaining_accounts, params)
+}
+
+pub(crate) fn jit_inner<'c: 'info, 'info>(
+    accounts: &mut Jit<'info>,
+    remaining_accounts: &'c [AccountInfo<'info>],
+    params: JitParams,
 ) -> Result<()> {
     let clock = Clock::get()?;
     let slot = clock.slot;
 
-    let taker = ctx.accounts.taker.load()?;
-    let maker = ctx.accounts.user.load()?;
+    check_expiry(
+        params.valid_until_slot,
+        params.max_ts,
+        slot,
+        clock.unix_timestamp,
+    )?;
 
-    let state = &ctx.accounts.state;
+    let taker = accounts.taker.load()?;
+    let maker = accounts.user.load()?;
 
-    let remaining_accounts_iter = &mut ctx.remaining_accounts.iter().peekable();
+    let state = &accounts.state;
+
+    let remaining_accounts_iter = &mut remaining_accounts.iter().peekable();
     let AccountMaps {
         perp_market_map,
         spot_market_map,
@@ -52,19 +77,60 @@ pub fn jit<'c: 'info, 'info>(
     let market_type = taker_order.market_type;
     let market_index = taker_order.market_index;
 
-    let oracle_price = if taker_order.market_type == DriftMarketType::Perp {
-        let perp_market = perp_market_map.get_ref(&taker_order.market_index)?;
-        perp_market
-            .get_mm_oracle_price_data(
-                *oracle_map.get_price_data(&perp_market.oracle_id())?,
-                clock.slot,
-                &state.oracle_guard_rails.validity,
-            )?
-            .get_price()
-    } else {
-        let spot_market = spot_market_map.get_ref(&taker_order.market_index)?;
-        oracle_map.get_price_data(&spot_market.oracle_id())?.price
-    };
+    check_maker_control(&accounts.maker_control, market_type, market_index)?;
+
+    accounts.risk_ledger.update_window(clock.unix_timestamp)?;
+    accounts.risk_ledger.check_limits()?;
+
+    // snapshot the maker position so the fill summary and risk ledger use the actual change
+    let position_init =
+        get_base_and_quote_amounts(&maker, market_type, market_index, &spot_market_map)?;
+
+    let (oracle_price, oracle_price_data) = get_oracle_price(
+        taker_order,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+        state,
+        slot,
+    )?;
+
+    check_oracle_guards(
+        &oracle_price_data,
+        params.max_oracle_confidence_bps,
+        params.max_oracle_delay_slots,
+    )?;
+
+    check_oracle_deviation(
+        oracle_price,
+        params.expected_oracle_price,
+        params.max_oracle_deviation_bps,
+    )?;
+
+    let reference_price = get_reference_price(
+        params.price_type,
+        taker_order,
+        &perp_market_map,
+        &spot_market_map,
+        oracle_price,
+    )?;
+
+    let taker_base_asset_amount_unfilled_init = taker_order.get_base_asset_amount_unfilled(None)?;
+
+    let mut maker_worst_price = params.get_worst_price(
+        reference_price,
+        taker_order.direction,
+        taker_base_asset_amount_unfilled_init,
+    )?;
+    if params.fee_aware {
+        maker_worst_price = get_fee_adjusted_worst_price(
+            maker_worst_price,
+            taker_order,
+            &*accounts.user_stats.load()?,
+            state,
+            &perp_market_map,
+        )?;
+    }
 
     let (order_params, taker_base_asset_amount_unfilled, taker_price, maker_price) = process_order(
         &maker,
@@ -75,16 +141,27 @@ pub fn jit<'c: 'info, 'info>(
         params.max_position,
         params.min_position,
         oracle_price,
-        params.get_worst_price(oracle_price, taker_order.direction)?,
+        maker_worst_price,
         params.post_only.unwrap_or(PostOnlyParam::MustPostOnly),
+        params.max_fill_size,
+        params.min_fill_size,
+        params.skew_per_base,
+        params.min_edge_bps,
+        params.fill_mode,
+        params.reduce_only,
     )?;
 
     drop(taker);
     drop(maker);
 
-    place_and_make(&ctx, params.taker_order_id, order_params)?;
+    place_and_make(
+        accounts,
+        remaining_accounts,
+        params.taker_order_id,
+        order_params,
+    )?;
 
-    let taker = ctx.accounts.taker.load()?;
+    let taker = accounts.taker.load()?;
 
     let taker_base_asset_amount_unfilled_after = match taker.get_order(params.taker_order_id) {
         Some(order) => order.get_base_asset_amount_unfilled(None)?,
@@ -100,19 +177,64 @@ pub fn jit<'c: 'info, 'info>(
             oracle_price
         );
         msg!("jit params {:?}", params);
-        if market_type == DriftMarketType::Perp {
-            let perp_market = perp_market_map.get_ref(&market_index)?;
-            let reserve_price = perp_market.amm.reserve_price()?;
-            let (bid_price, ask_price) = perp_market.amm.bid_ask_price(reserve_price)?;
-            msg!(
-                "vamm bid price = {} vamm ask price = {}",
-                bid_price,
-                ask_price
-            );
-        }
+        let (vamm_bid_price, vamm_ask_price) =
+            get_vamm_bid_ask_price(market_type, market_index, &perp_market_map)?;
+        emit!(JitNoFillEvent {
+            taker: accounts.taker.key(),
+            maker: accounts.user.key(),
+            market_type: market_type.into(),
+            market_index,
+            taker_direction: order_params.direction.opposite().into(),
+            taker_price,
+            maker_price,
+            oracle_price,
+            vamm_bid_price,
+            vamm_ask_price,
+            slot,
+        });
         return Err(ErrorCode::NoFill.into());
     }
 
+    if params.fill_mode == FillMode::FillOrKill && taker_base_asset_amount_unfilled_after != 0 {
+        msg!(
+            "fill or kill taker order has {} unfilled",
+            taker_base_asset_amount_unfilled_after
+        );
+        return Err(ErrorCode::FillOrKillNotFilled.into());
+    }
+
+    let base_asset_amount_filled =
+        taker_base_asset_amount_unfilled_init.safe_sub(taker_base_asset_amount_unfilled_after)?;
+
+    emit!(JitFillEvent {
+        taker: accounts.taker.key(),
+        maker: accounts.user.key(),
+        market_type: market_type.into(),
+        market_index,
+        taker_direction: order_params.direction.opposite().into(),
+        taker_price,
+        maker_price,
+        oracle_price,
+        base_asset_amount_filled,
+        slot,
+    });
+
+    let maker = accounts.user.load()?;
+    let position_end =
+        get_base_and_quote_amounts(&maker, market_type, market_index, &spot_market_map)?;
+    let base_precision = get_base_precision(market_type, market_index, &spot_market_map)?;
+
+    record_fill(
+        &mut accounts.risk_ledger,
+        position_init,
+        position_end,
+        base_precision,
+        oracle_price,
+    )?;
+
+    let summary = calculate_fill_summary(position_init, position_end, base_precision)?;
+    set_return_data(&summary.try_to_vec()?);
+
     Ok(())
 }
 
@@ -123,6 +245,13 @@ pub fn jit_signed_msg<'c: 'info, 'info>(
     let clock = Clock::get()?;
     let slot = clock.slot;
 
+    check_expiry(
+        params.valid_until_slot,
+        params.max_ts,
+        slot,
+        clock.unix_timestamp,
+    )?;
+
     let taker = ctx.accounts.taker.load()?;
     let maker = ctx.accounts.user.load()?;
 
@@ -138,6 +267,17 @@ pub fn jit_signed_msg<'c: 'info, 'info>(
         .get_order(taker_order_id)
         .ok_or(ErrorCode::TakerOrderNotFound)?;
 
+    check_maker_control(
+        &ctx.accounts.maker_control,
+        taker_order.market_type,
+        taker_order.market_index,
+    )?;
+
+    ctx.accounts
+        .risk_ledger
+        .update_window(clock.unix_timestamp)?;
+    ctx.accounts.risk_ledger.check_limits()?;
+
     let remaining_accounts_iter = &mut ctx.remaining_accounts.iter().peekable();
     let AccountMaps {
         perp_market_map,
@@ -151,15 +291,43 @@ pub fn jit_signed_msg<'c: 'info, 'info>(
         None,
     )?;
 
-    let perp_market = perp_market_map.get_ref(&taker_order.market_index)?;
-    let oracle_price = perp_market
-        .get_mm_oracle_price_data(
-            *oracle_map.get_price_data(&perp_market.oracle_id())?,
-            clock.slot,
-            &state.oracle_guard_rails.validity,
-        )?
-        .get_price();
-    drop(perp_market);
+    let (oracle_price, oracle_price_data) = get_oracle_price(
+        taker_order,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+        state,
+        slot,
+    )?;
+
+    check_oracle_guards(
+        &oracle_price_data,
+        params.max_oracle_confidence_bps,
+        params.max_oracle_delay_slots,
+    )?;
+
+    check_oracle_deviation(
+        oracle_price,
+        params.expected_oracle_price,
+        params.max_oracle_deviation_bps,
+    )?;
+
+    let reference_price = get_reference_price(
+        params.price_type,
+        taker_order,
+        &perp_market_map,
+        &spot_market_map,
+        oracle_price,
+    )?;
+
+    let taker_base_asset_amount_unfilled_init = taker_order.get_base_asset_amount_unfilled(None)?;
+
+    let position_init = get_base_and_quote_amounts(
+        &maker,
+        taker_order.market_type,
+        taker_order.market_index,
+        &spot_market_map,
+    )?;
 
     let (order_params, taker_base_asset_amount_unfilled, taker_price, maker_price) = process_order(
         &maker,
@@ -170,14 +338,25 @@ pub fn jit_signed_msg<'c: 'info, 'info>(
         params.max_position,
         params.min_position,
         oracle_price,
-        params.get_worst_price(oracle_price, taker_order.direction)?,
+        params.get_worst_price(reference_price, taker_order.direction)?,
         params.post_only.unwrap_or(PostOnlyParam::MustPostOnly),
+        None,
+        None,
+        None,
+        None,
+        FillMode::Partial,
+        params.reduce_only,
     )?;
 
     drop(taker);
     drop(maker);
 
-    place_and_make_signed_msg(&ctx, order_params, params.signed_msg_order_uuid)?;
+    place_and_make_signed_msg(
+        &ctx,
+        order_params,
+        taker_order_id,
+        params.signed_msg_order_uuid,
+    )?;
 
     let taker = ctx.accounts.taker.load()?;
 
@@ -195,22 +374,715 @@ pub fn jit_signed_msg<'c: 'info, 'info>(
             oracle_price
         );
         msg!("jit params {:?}", params);
+        let (vamm_bid_price, vamm_ask_price) = get_vamm_bid_ask_price(
+            order_params.market_type,
+            order_params.market_index,
+            &perp_market_map,
+        )?;
+        emit!(JitNoFillEvent {
+            taker: ctx.accounts.taker.key(),
+            maker: ctx.accounts.user.key(),
+            market_type: order_params.market_type.into(),
+            market_index: order_params.market_index,
+            taker_direction: order_params.direction.opposite().into(),
+            taker_price,
+            maker_price,
+            oracle_price,
+            vamm_bid_price,
+            vamm_ask_price,
+            slot,
+        });
 
-        let perp_market = perp_market_map.get_ref(&order_params.market_index)?;
-        let reserve_price = perp_market.amm.reserve_price()?;
-        let (bid_price, ask_price) = perp_market.amm.bid_ask_price(reserve_price)?;
+        return Err(ErrorCode::NoFill.into());
+    }
+
+    let base_asset_amount_filled =
+        taker_base_asset_amount_unfilled_init.safe_sub(taker_base_asset_amount_unfilled_after)?;
+
+    emit!(JitFillEvent {
+        taker: ctx.accounts.taker.key(),
+        maker: ctx.accounts.user.key(),
+        market_type: order_params.market_type.into(),
+        market_index: order_params.market_index,
+        taker_direction: order_params.direction.opposite().into(),
+        taker_price,
+        maker_price,
+        oracle_price,
+        base_asset_amount_filled,
+        slot,
+    });
+
+    let maker = ctx.accounts.user.load()?;
+    let position_end = get_base_and_quote_amounts(
+        &maker,
+        order_params.market_type,
+        order_params.market_index,
+        &spot_market_map,
+    )?;
+    let base_precision = get_base_precision(
+        order_params.market_type,
+        order_params.market_index,
+        &spot_market_map,
+    )?;
+
+    record_fill(
+        &mut ctx.accounts.risk_ledger,
+        position_init,
+        position_end,
+        base_precision,
+        oracle_price,
+    )?;
+
+    let summary = calculate_fill_summary(position_init, position_end, base_precision)?;
+    set_return_data(&summary.try_to_vec()?);
+
+    Ok(())
+}
+
+pub fn jit_batch<'c: 'info, 'info>(
+    mut ctx: Context<'_, '_, 'c, 'info, JitBatch<'info>>,
+    params: JitBatchParams,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let slot = clock.slot;
+
+    ctx.accounts
+        .risk_ledger
+        .update_window(clock.unix_timestamp)?;
+
+    let remaining_accounts = ctx.remaining_accounts;
+    let remaining_accounts_iter = &mut remaining_accounts.iter().peekable();
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = load_maps(
+        remaining_accounts_iter,
+        &BTreeSet::new(),
+        &BTreeSet::new(),
+        slot,
+        None,
+    )?;
+
+    // the market and oracle accounts are forwarded to drift for every entry, followed by the
+    // entry's extra accounts. the taker accounts after them are passed as the taker for each entry
+    let market_accounts_len = remaining_accounts
+        .len()
+        .safe_sub(remaining_accounts_iter.len())?;
+    let market_accounts = &remaining_accounts[..market_accounts_len];
+
+    let mut filled = false;
+    for entry in params.entries.iter() {
+        // earlier entries count towards the limits, so they're checked before every entry and a
+        // breach fails the batch even in best effort mode
+        ctx.accounts.risk_ledger.check_limits()?;
+
+        // only errors before the drift cpi can be skipped, once it has run a partial fill can't be
+        // undone so errors after it always fail the batch
+        let order = match prepare_jit_batch_entry(
+            &ctx,
+            &perp_market_map,
+            &spot_market_map,
+            &mut oracle_map,
+            entry,
+            slot,
+            clock.unix_timestamp,
+        ) {
+            Ok(order) => order,
+            Err(e) if params.best_effort => {
+                msg!(
+                    "skipping taker index {} order {}: {:?}",
+                    entry.taker_index,
+                    entry.params.taker_order_id,
+                    e
+                );
+                continue;
+            }
+            Err(e) => return Err(e),
+        };
+
+        if fill_jit_batch_entry(
+            &mut ctx,
+            market_accounts,
+            &perp_market_map,
+            &spot_market_map,
+            entry,
+            order,
+            slot,
+        )? {
+            filled = true;
+        } else if !params.best_effort {
+            return Err(ErrorCode::NoFill.into());
+        }
+    }
+
+    if !filled {
+        return Err(ErrorCode::NoFill.into());
+    }
+
+    Ok(())
+}
+
+/// A jit_batch entry that passed the checks before the drift cpi
+struct JitBatchOrder<'c, 'info> {
+    taker: &'c AccountInfo<'info>,
+    taker_stats: &'c AccountInfo<'info>,
+    extra_accounts: &'c [AccountInfo<'info>],
+    order_params: OrderParams,
+    taker_base_asset_amount_unfilled_init: u64,
+    taker_base_asset_amount_unfilled: u64,
+    taker_price: u64,
+    maker_price: u64,
+    oracle_price: i64,
+    position_init: (i128, i128),
+}
+
+fn prepare_jit_batch_entry<'c: 'info, 'info>(
+    ctx: &Context<'_, '_, 'c, 'info, JitBatch<'info>>,
+    perp_market_map: &PerpMarketMap,
+    spot_market_map: &SpotMarketMap,
+    oracle_map: &mut OracleMap,
+    entry: &JitBatchEntry,
+    slot: u64,
+    now: i64,
+) -> Result<JitBatchOrder<'c, 'info>> {
+    let params = &entry.params;
+
+    check_expiry(params.valid_until_slot, params.max_ts, slot, now)?;
+    let taker_index = entry.taker_index as usize;
+
+    let remaining_accounts = ctx.remaining_accounts;
+    let taker_account_info = remaining_accounts
+        .get(taker_index)
+        .ok_or(ErrorCode::TakerAccountNotFound)?;
+    let taker_stats_account_info = remaining_accounts
+        .get(taker_index + 1)
+        .ok_or(ErrorCode::TakerAccountNotFound)?;
+
+    let extra_accounts_start = entry.extra_accounts_index as usize;
+    let extra_accounts_end = extra_accounts_start + entry.extra_accounts_len as usize;
+    let extra_accounts = remaining_accounts
+        .get(extra_accounts_start..extra_accounts_end)
+        .ok_or(ErrorCode::ExtraAccountsNotFound)?;
+
+    let taker_loader: AccountLoader<User> = AccountLoader::try_from(taker_account_info)?;
+
+    // the maker is reloaded for every entry so the position limits account for earlier fills
+    let taker = taker_loader.load()?;
+    let maker = ctx.accounts.user.load()?;
+
+    let taker_order = taker
+        .get_order(params.taker_order_id)
+        .ok_or(ErrorCode::TakerOrderNotFound)?;
+
+    check_maker_control(
+        &ctx.accounts.maker_control,
+        taker_order.market_type,
+        taker_order.market_index,
+    )?;
+
+    let (oracle_price, oracle_price_data) = get_oracle_price(
+        taker_order,
+        perp_market_map,
+        spot_market_map,
+        oracle_map,
+        &ctx.accounts.state,
+        slot,
+    )?;
+
+    check_oracle_guards(
+        &oracle_price_data,
+        params.max_oracle_confidence_bps,
+        params.max_oracle_delay_slots,
+    )?;
+
+    check_oracle_deviation(
+        oracle_price,
+        params.expected_oracle_price,
+        params.max_oracle_deviation_bps,
+    )?;
+
+    let reference_price = get_reference_price(
+        params.price_type,
+        taker_order,
+        perp_market_map,
+        spot_market_map,
+        oracle_price,
+    )?;
+
+    let taker_base_asset_amount_unfilled_init = taker_order.get_base_asset_amount_unfilled(None)?;
+
+    let mut maker_worst_price = params.get_worst_price(
+        reference_price,
+        taker_order.direction,
+        taker_base_asset_amount_unfilled_init,
+    )?;
+    if params.fee_aware {
+        maker_worst_price = get_fee_adjusted_worst_price(
+            maker_worst_price,
+            taker_order,
+            &*ctx.accounts.user_stats.load()?,
+            &ctx.accounts.state,
+            perp_market_map,
+        )?;
+    }
+
+    let position_init = get_base_and_quote_amounts(
+        &maker,
+        taker_order.market_type,
+        taker_order.market_index,
+        spot_market_map,
+    )?;
+
+    let (order_params, taker_base_asset_amount_unfilled, taker_price, maker_price) = process_order(
+        &maker,
+        perp_market_map,
+        spot_market_map,
+        taker_order,
+        slot,
+        params.max_position,
+        params.min_position,
+        oracle_price,
+        maker_worst_price,
+        params.post_only.unwrap_or(PostOnlyParam::MustPostOnly),
+        params.max_fill_size,
+        params.min_fill_size,
+        params.skew_per_base,
+        params.min_edge_bps,
+        params.fill_mode,
+        params.reduce_only,
+    )?;
+
+    Ok(JitBatchOrder {
+        taker: taker_account_info,
+        taker_stats: taker_stats_account_info,
+        extra_accounts,
+        order_params,
+        taker_base_asset_amount_unfilled_init,
+        taker_base_asset_amount_unfilled,
+        taker_price,
+        maker_price,
+        oracle_price,
+        position_init,
+    })
+}
+
+/// Returns false without an error if the taker order didn't fill
+#[allow(clippy::too_many_arguments)]
+fn fill_jit_batch_entry<'c: 'info, 'info>(
+    ctx: &mut Context<'_, '_, 'c, 'info, JitBatch<'info>>,
+    market_accounts: &[AccountInfo<'info>],
+    perp_market_map: &PerpMarketMap,
+    spot_market_map: &SpotMarketMap,
+    entry: &JitBatchEntry,
+    order: JitBatchOrder<'c, 'info>,
+    slot: u64,
+) -> Result<bool> {
+    let params = &entry.params;
+    let JitBatchOrder {
+        taker: taker_account_info,
+        taker_stats: taker_stats_account_info,
+        extra_accounts,
+        order_params,
+        taker_base_asset_amount_unfilled_init,
+        taker_base_asset_amount_unfilled,
+        taker_price,
+        maker_price,
+        oracle_price,
+        position_init,
+    } = order;
+
+    place_and_make_batch(
+        ctx,
+        taker_account_info,
+        taker_stats_account_info,
+        market_accounts,
+        extra_accounts,
+        params.taker_order_id,
+        order_params,
+    )?;
+
+    let taker_loader: AccountLoader<User> = AccountLoader::try_from(taker_account_info)?;
+    let taker = taker_loader.load()?;
+
+    let taker_base_asset_amount_unfilled_after = match taker.get_order(params.taker_order_id) {
+        Some(order) => order.get_base_asset_amount_unfilled(None)?,
+        None => 0,
+    };
+
+    if taker_base_asset_amount_unfilled_after == taker_base_asset_amount_unfilled {
+        // taker order failed to fill
+        msg!(
+            "taker price = {} maker price = {} oracle price = {}",
+            taker_price,
+            maker_price,
+            oracle_price
+        );
+        msg!("jit params {:?}", params);
+        let (vamm_bid_price, vamm_ask_price) = get_vamm_bid_ask_price(
+            order_params.market_type,
+            order_params.market_index,
+            perp_market_map,
+        )?;
+        emit!(JitNoFillEvent {
+            taker: *taker_account_info.key,
+            maker: ctx.accounts.user.key(),
+            market_type: order_params.market_type.into(),
+            market_index: order_params.market_index,
+            taker_direction: order_params.direction.opposite().into(),
+            taker_price,
+            maker_price,
+            oracle_price,
+            vamm_bid_price,
+            vamm_ask_price,
+            slot,
+        });
+        return Ok(false);
+    }
+
+    if params.fill_mode == FillMode::FillOrKill && taker_base_asset_amount_unfilled_after != 0 {
         msg!(
-            "vamm bid price = {} vamm ask price = {}",
-            bid_price,
-            ask_price
+            "fill or kill taker order has {} unfilled",
+            taker_base_asset_amount_unfilled_after
         );
+        return Err(ErrorCode::FillOrKillNotFilled.into());
+    }
 
-        return Err(ErrorCode::NoFill.into());
+    emit!(JitFillEvent {
+        taker: *taker_account_info.key,
+        maker: ctx.accounts.user.key(),
+        market_type: order_params.market_type.into(),
+        market_index: order_params.market_index,
+        taker_direction: order_params.direction.opposite().into(),
+        taker_price,
+        maker_price,
+        oracle_price,
+        base_asset_amount_filled: taker_base_asset_amount_unfilled_init
+            .safe_sub(taker_base_asset_amount_unfilled_after)?,
+        slot,
+    });
+
+    let position_end = get_base_and_quote_amounts(
+        &*ctx.accounts.user.load()?,
+        order_params.market_type,
+        order_params.market_index,
+        spot_market_map,
+    )?;
+    let base_precision = get_base_precision(
+        order_params.market_type,
+        order_params.market_index,
+        spot_market_map,
+    )?;
+
+    record_fill(
+        &mut ctx.accounts.risk_ledger,
+        position_init,
+        position_end,
+        base_precision,
+        oracle_price,
+    )?;
+
+    Ok(true)
+}
+
+/// Returns the oracle price and the price data it was taken from. For perps this is whichever of
+/// the mm oracle and exchange oracle drift would use to fill, so the guards check the same source
+fn get_oracle_price(
+    taker_order: &Order,
+    perp_market_map: &PerpMarketMap,
+    spot_market_map: &SpotMarketMap,
+    oracle_map: &mut OracleMap,
+    state: &State,
+    slot: u64,
+) -> Result<(i64, OraclePriceData)> {
+    if taker_order.market_type == DriftMarketType::Perp {
+        let perp_market = perp_market_map.get_ref(&taker_order.market_index)?;
+        let oracle_price_data = perp_market
+            .get_mm_oracle_price_data(
+                *oracle_map.get_price_data(&perp_market.oracle_id())?,
+                slot,
+                &state.oracle_guard_rails.validity,
+            )?
+            .get_safe_oracle_price_data();
+
+        Ok((oracle_price_data.price, oracle_price_data))
+    } else {
+        let spot_market = spot_market_map.get_ref(&taker_order.market_index)?;
+        let oracle_price_data = *oracle_map.get_price_data(&spot_market.oracle_id())?;
+
+        Ok((oracle_price_data.price, oracle_price_data))
+    }
+}
+
+/// Converts a worst price net of the maker rebate into the gross order price
+fn get_fee_adjusted_worst_price(
+    maker_worst_price: u64,
+    taker_order: &Order,
+    maker_stats: &UserStats,
+    state: &State,
+    perp_market_map: &PerpMarketMap,
+) -> Result<u64> {
+    let (fee_structure, fee_adjustment) = if taker_order.market_type == DriftMarketType::Perp {
+        let perp_market = perp_market_map.get_ref(&taker_order.market_index)?;
+        (&state.perp_fee_structure, perp_market.fee_adjustment)
+    } else {
+        (&state.spot_fee_structure, 0)
+    };
+
+    // high leverage mode only changes the taker fee
+    let fee_tier =
+        determine_user_fee_tier(maker_stats, fee_structure, &taker_order.market_type, false)?;
+
+    let fee_adjusted_price = calculate_fee_adjusted_price(
+        maker_worst_price,
+        taker_order.direction.opposite(),
+        &fee_tier,
+        fee_adjustment,
+    )?;
+
+    msg!(
+        "fee adjusted worst price {} -> {}",
+        maker_worst_price,
+        fee_adjusted_price
+    );
+
+    Ok(fee_adjusted_price)
+}
+
+/// The maker pays price * (1 - rebate) on a bid and receives price * (1 + rebate) on an ask,
+/// so the gross bid is bid / (1 - rebate) and the gross ask is ask / (1 + rebate)
+fn calculate_fee_adjusted_price(
+    net_price: u64,
+    maker_direction: PositionDirection,
+    fee_tier: &FeeTier,
+    fee_adjustment: i16,
+) -> DriftResult<u64> {
+    // fee adjustment scales the rebate by (100 + fee_adjustment) / 100
+    let rebate_numerator = fee_tier
+        .maker_rebate_numerator
+        .cast::<i128>()?
+        .safe_mul(100_i128.safe_add(fee_adjustment.cast()?)?)?;
+    let rebate_denominator = fee_tier
+        .maker_rebate_denominator
+        .cast::<i128>()?
+        .safe_mul(100)?;
+
+    let gross_denominator = match maker_direction {
+        PositionDirection::Long => rebate_denominator.safe_sub(rebate_numerator)?,
+        PositionDirection::Short => rebate_denominator.safe_add(rebate_numerator)?,
+    };
+
+    net_price
+        .cast::<i128>()?
+        .safe_mul(rebate_denominator)?
+        .safe_div(gross_denominator)?
+        .cast()
+}
+
+fn check_expiry(
+    valid_until_slot: Option<u64>,
+    max_ts: Option<i64>,
+    slot: u64,
+    now: i64,
+) -> Result<()> {
+    if let Some(valid_until_slot) = valid_until_slot {
+        if slot > valid_until_slot {
+            msg!("slot {} > valid until slot {}", slot, valid_until_slot);
+            return Err(ErrorCode::Expired.into());
+        }
+    }
+
+    if let Some(max_ts) = max_ts {
+        if now > max_ts {
+            msg!("now {} > max ts {}", now, max_ts);
+            return Err(ErrorCode::Expired.into());
+        }
+    }
+
+    Ok(())
+}
+
+fn check_oracle_deviation(
+    oracle_price: i64,
+    expected_oracle_price: Option<i64>,
+    max_oracle_deviation_bps: Option<u64>,
+) -> Result<()> {
+    if expected_oracle_price.is_some() != max_oracle_deviation_bps.is_some() {
+        msg!(
+            "expected oracle price {:?} and max oracle deviation bps {:?} must be set together",
+            expected_oracle_price,
+            max_oracle_deviation_bps
+        );
+        return Err(ErrorCode::InvalidOracleDeviationParams.into());
+    }
+
+    if let (Some(expected_oracle_price), Some(max_oracle_deviation_bps)) =
+        (expected_oracle_price, max_oracle_deviation_bps)
+    {
+        let deviation_bps = oracle_price
+            .cast::<i128>()?
+            .safe_sub(expected_oracle_price.cast()?)?
+            .unsigned_abs()
+            .safe_mul(BPS_PRECISION.cast()?)?
+            .safe_div(expected_oracle_price.unsigned_abs().max(1).cast()?)?;
+
+        if deviation_bps > max_oracle_deviation_bps.cast()? {
+            msg!(
+                "oracle price {} expected {} deviation {} bps > max {} bps",
+                oracle_price,
+                expected_oracle_price,
+                deviation_bps,
+                max_oracle_deviation_bps
+            );
+            return Err(ErrorCode::OracleDeviationTooLarge.into());
+        }
+    }
+
+    Ok(())
+}
+
+fn check_oracle_guards(
+    oracle_price_data: &OraclePriceData,
+    max_oracle_confidence_bps: Option<u64>,
+    max_oracle_delay_slots: Option<u64>,
+) -> Result<()> {
+    if let Some(max_oracle_confidence_bps) = max_oracle_confidence_bps {
+        let confidence_bps = oracle_price_data
+            .confidence
+            .cast::<u128>()?
+            .safe_mul(BPS_PRECISION.cast()?)?
+            .safe_div(oracle_price_data.price.unsigned_abs().max(1).cast()?)?;
+
+        if confidence_bps > max_oracle_confidence_bps.cast()? {
+            msg!(
+                "oracle confidence {} bps > max {} bps",
+                confidence_bps,
+                max_oracle_confidence_bps
+            );
+            return Err(ErrorCode::OracleConfidenceTooWide.into());
+        }
+    }
+
+    if let Some(max_oracle_delay_slots) = max_oracle_delay_slots {
+        if oracle_price_data.delay > max_oracle_delay_slots.cast()? {
+            msg!(
+                "oracle delay {} slots > max {} slots",
+                oracle_price_data.delay,
+                max_oracle_delay_slots
+            );
+            return Err(ErrorCode::OracleTooStale.into());
+        }
     }
 
     Ok(())
 }
 
+/// Returns the fill summary from the change in the maker base and quote amounts
+fn calculate_fill_summary(
+    position_init: (i128, i128),
+    position_end: (i128, i128),
+    base_precision: u128,
+) -> DriftResult<JitFillSummary> {
+    let base_delta = position_end.0.safe_sub(position_init.0)?;
+    let quote_delta = position_end.1.safe_sub(position_init.1)?;
+
+    let fill_price = if base_delta == 0 {
+        0
+    } else {
+        quote_delta
+            .unsigned_abs()
+            .safe_mul(base_precision)?
+            .safe_mul(PRICE_PRECISION)?
+            .safe_div(QUOTE_PRECISION)?
+            .safe_div(base_delta.unsigned_abs())?
+            .cast()?
+    };
+
+    Ok(JitFillSummary {
+        base_asset_amount_filled: base_delta.unsigned_abs().cast()?,
+        fill_price,
+        maker_position: position_end.0.cast()?,
+    })
+}
+
+/// Returns the maker perp base asset amount or signed spot token amount
+fn get_maker_position(
+    maker: &User,
+    market_type: DriftMarketType,
+    market_index: u16,
+    spot_market_map: &SpotMarketMap,
+) -> Result<i64> {
+    if market_type == DriftMarketType::Perp {
+        let perp_position = maker.get_perp_position(market_index);
+        match perp_position {
+            Ok(perp_position) => Ok(perp_position.base_asset_amount),
+            Err(_) => Ok(0),
+        }
+    } else {
+        let spot_market = spot_market_map.get_ref(&market_index)?;
+        Ok(maker
+            .get_spot_position(market_index)
+            .map_or(Ok(0), |p| p.get_signed_token_amount(&spot_market))?
+            .cast::<i64>()?)
+    }
+}
+
+/// Returns the vamm bid and ask for perp markets, logging them for failed fills
+fn get_vamm_bid_ask_price(
+    market_type: DriftMarketType,
+    market_index: u16,
+    perp_market_map: &PerpMarketMap,
+) -> Result<(Option<u64>, Option<u64>)> {
+    if market_type != DriftMarketType::Perp {
+        return Ok((None, None));
+    }
+
+    let perp_market = perp_market_map.get_ref(&market_index)?;
+    let reserve_price = perp_market.amm.reserve_price()?;
+    let (bid_price, ask_price) = perp_market.amm.bid_ask_price(reserve_price)?;
+    msg!(
+        "vamm bid price = {} vamm ask price = {}",
+        bid_price,
+        ask_price
+    );
+
+    Ok((Some(bid_price), Some(ask_price)))
+}
+
+/// Returns the price the bid/ask offsets are relative to for the given price type
+fn get_reference_price(
+    price_type: PriceType,
+    taker_order: &Order,
+    perp_market_map: &PerpMarketMap,
+    spot_market_map: &SpotMarketMap,
+    oracle_price: i64,
+) -> Result<i64> {
+    let reference_price = match (price_type, taker_order.market_type) {
+        (PriceType::Limit | PriceType::Oracle | PriceType::OracleBps, _) => oracle_price,
+        (PriceType::Amm, DriftMarketType::Perp) => {
+            let perp_market = perp_market_map.get_ref(&taker_order.market_index)?;
+            perp_market.amm.reserve_price()?.cast()?
+        }
+        (PriceType::Amm, DriftMarketType::Spot) => {
+            msg!("spot markets have no amm to price against");
+            return Err(ErrorCode::PriceTypeNotSupported.into());
+        }
+        (PriceType::OracleTwap, DriftMarketType::Perp) => {
+            let perp_market = perp_market_map.get_ref(&taker_order.market_index)?;
+            perp_market
+                .amm
+                .historical_oracle_data
+                .last_oracle_price_twap
+        }
+        (PriceType::OracleTwap, DriftMarketType::Spot) => {
+            let spot_market = spot_market_map.get_ref(&taker_order.market_index)?;
+            spot_market.historical_oracle_data.last_oracle_price_twap
+        }
+    };
+
+    Ok(reference_price)
+}
+
 #[allow(clippy::too_many_arguments)]
 #[inline(always)]
 fn process_order(
@@ -224,6 +1096,12 @@ fn process_order(
     oracle_price: i64,
     maker_worst_price: u64,
     post_only: PostOnlyParam,
+    max_fill_size: Option<u64>,
+    min_fill_size: Option<u64>,
+    skew_per_base: Option<i64>,
+    min_edge_bps: Option<u64>,
+    fill_mode: FillMode,
+    reduce_only: bool,
 ) -> Result<(OrderParams, u64, u64, u64)> {
     let market_type = taker_order.market_type;
     let market_index = taker_order.market_index;
@@ -250,23 +1128,47 @@ fn process_order(
         taker_order.oracle_price_offset
     );
 
-    let (tick_size, min_order_size, is_prediction_market) = if market_type == DriftMarketType::Perp
-    {
-        let perp_market = perp_market_map.get_ref(&market_index)?;
-
-        (
-            perp_market.amm.order_tick_size,
-            perp_market.amm.min_order_size,
-            perp_market.is_prediction_market(),
-        )
-    } else {
-        let spot_market = spot_market_map.get_ref(&market_index)?;
+    let (tick_size, min_order_size, is_prediction_market, base_precision) =
+        if market_type == DriftMarketType::Perp {
+            let perp_market = perp_market_map.get_ref(&market_index)?;
 
-        (
-            spot_market.order_tick_size,
-            spot_market.min_order_size,
-            false,
-        )
+            (
+                perp_market.amm.order_tick_size,
+                perp_market.amm.min_order_size,
+                perp_market.is_prediction_market(),
+                BASE_PRECISION,
+            )
+        } else {
+            let spot_market = spot_market_map.get_ref(&market_index)?;
+
+            (
+                spot_market.order_tick_size,
+                spot_market.min_order_size,
+                false,
+                spot_market.get_precision().cast()?,
+            )
+        };
+
+    let maker_existing_position =
+        get_maker_position(maker, market_type, market_index, spot_market_map)?;
+
+    let maker_worst_price = match skew_per_base {
+        Some(skew_per_base) => {
+            let skewed_price = apply_skew(
+                maker_worst_price,
+                maker_existing_position,
+                skew_per_base,
+                base_precision,
+            )?;
+            msg!(
+                "maker position {} skewed worst price {} -> {}",
+                maker_existing_position,
+                maker_worst_price,
+                skewed_price
+            );
+            skewed_price
+        }
+        None => maker_worst_price,
     };
 
     let taker_price = match taker_order.get_limit_price(
@@ -348,30 +1250,35 @@ fn process_order(
         taker_price
     };
 
+    if let Some(min_edge_bps) = min_edge_bps {
+        check_min_edge(maker_price, oracle_price, maker_direction, min_edge_bps)?;
+    }
+
     let taker_base_asset_amount_unfilled = taker_order
         .get_base_asset_amount_unfilled(None)?
         .max(min_order_size);
-    let maker_existing_position = if market_type == DriftMarketType::Perp {
-        let perp_position = maker.get_perp_position(market_index);
-        match perp_position {
-            Ok(perp_position) => perp_position.base_asset_amount,
-            Err(_) => 0,
-        }
+
+    // clamp before the position limits so the min size checks run on the reduce only size
+    let maker_base_asset_amount_limit = if reduce_only {
+        check_reduce_only(
+            maker_direction,
+            maker_existing_position,
+            taker_base_asset_amount_unfilled,
+            min_order_size,
+        )?
     } else {
-        let spot_market = spot_market_map.get_ref(&market_index)?;
-        maker
-            .get_spot_position(market_index)
-            .map_or(0, |p| p.get_signed_token_amount(&spot_market).unwrap())
-            .cast::<i64>()?
+        taker_base_asset_amount_unfilled
     };
 
     let maker_base_asset_amount = match check_position_limits(
         max_position,
         min_position,
         maker_direction,
-        taker_base_asset_amount_unfilled,
+        maker_base_asset_amount_limit,
         maker_existing_position,
         min_order_size,
+        max_fill_size,
+        min_fill_size,
     ) {
         Ok(size) => size,
         Err(e) => {
@@ -379,6 +1286,17 @@ fn process_order(
         }
     };
 
+    if fill_mode == FillMode::FillOrKill
+        && maker_base_asset_amount < taker_base_asset_amount_unfilled
+    {
+        msg!(
+            "fill or kill maker size {} < taker unfilled {}",
+            maker_base_asset_amount,
+            taker_base_asset_amount_unfilled
+        );
+        return Err(ErrorCode::FillOrKillNotFilled.into());
+    }
+
     let order_params = OrderParams {
         order_type: OrderType::Limit,
         market_type,
@@ -387,7 +1305,7 @@ fn process_order(
         base_asset_amount: maker_base_asset_amount,
         price: maker_price,
         market_index,
-        reduce_only: false,
+        reduce_only,
         post_only,
         bit_flags: OrderParamsBitFlag::ImmediateOrCancel as u8,
         max_ts: None,
@@ -406,6 +1324,70 @@ fn process_order(
     ))
 }
 
+/// Checks the maker price is at least min_edge_bps better than the oracle price for the maker
+fn check_min_edge(
+    maker_price: u64,
+    oracle_price: i64,
+    maker_direction: PositionDirection,
+    min_edge_bps: u64,
+) -> Result<()> {
+    let edge = oracle_price
+        .unsigned_abs()
+        .cast::<u128>()?
+        .safe_mul(min_edge_bps.cast()?)?
+        .safe_div(BPS_PRECISION.cast()?)?
+        .cast::<u64>()?;
+
+    match maker_direction {
+        PositionDirection::Long => {
+            let max_bid = oracle_price.unsigned_abs().saturating_sub(edge);
+            if maker_price > max_bid {
+                msg!(
+                    "maker price {} > oracle price {} - min edge {}",
+                    maker_price,
+                    oracle_price,
+                    edge
+                );
+                return Err(ErrorCode::InsufficientEdge.into());
+            }
+        }
+        PositionDirection::Short => {
+            let min_ask = oracle_price.unsigned_abs().safe_add(edge)?;
+            if maker_price < min_ask {
+                msg!(
+                    "maker price {} < oracle price {} + min edge {}",
+                    maker_price,
+                    oracle_price,
+                    edge
+                );
+                return Err(ErrorCode::InsufficientEdge.into());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Shifts the worst price by position * skew_per_base so a long maker quotes lower and a short
+/// maker quotes higher
+fn apply_skew(
+    maker_worst_price: u64,
+    maker_existing_position: i64,
+    skew_per_base: i64,
+    base_precision: u128,
+) -> DriftResult<u64> {
+    let skew = maker_existing_position
+        .cast::<i128>()?
+        .safe_mul(skew_per_base.cast()?)?
+        .safe_div(base_precision.cast()?)?;
+
+    maker_worst_price
+        .cast::<i128>()?
+        .safe_sub(skew)?
+        .max(0)
+        .cast()
+}
+
 #[derive(Accounts)]
 pub struct Jit<'info> {
     pub state: Box<Account<'info, State>>,
@@ -419,6 +1401,17 @@ pub struct Jit<'info> {
     pub taker_stats: AccountLoader<'info, UserStats>,
     pub authority: Signer<'info>,
     pub drift_program: Program<'info, Drift>,
+    #[account(
+        seeds = [b"maker_control", user.key().as_ref()],
+        bump = maker_control.bump,
+    )]
+    pub maker_control: Option<Account<'info, MakerControl>>,
+    #[account(
+        mut,
+        seeds = [b"risk_ledger", user.key().as_ref()],
+        bump = risk_ledger.bump,
+    )]
+    pub risk_ledger: Account<'info, RiskLedger>,
 }
 
 #[derive(Accounts)]
@@ -437,9 +1430,44 @@ pub struct JitSignedMsg<'info> {
     pub taker_signed_msg_user_orders: AccountInfo<'info>,
     pub authority: Signer<'info>,
     pub drift_program: Program<'info, Drift>,
+    #[account(
+        seeds = [b"maker_control", user.key().as_ref()],
+        bump = maker_control.bump,
+    )]
+    pub maker_control: Option<Account<'info, MakerControl>>,
+
+    #[account(
+        mut,
+        seeds = [b"risk_ledger", user.key().as_ref()],
+        bump = risk_ledger.bump,
+    )]
+    pub risk_ledger: Account<'info, RiskLedger>,
 }
 
-#[derive(Debug, Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
+#[derive(Accounts)]
+pub struct JitBatch<'info> {
+    pub state: Box<Account<'info, State>>,
+    #[account(mut)]
+    pub user: AccountLoader<'info, User>,
+    #[account(mut)]
+    pub user_stats: AccountLoader<'info, UserStats>,
+    pub authority: Signer<'info>,
+    pub drift_program: Program<'info, Drift>,
+    #[account(
+        seeds = [b"maker_control", user.key().as_ref()],
+        bump = maker_control.bump,
+    )]
+    pub maker_control: Option<Account<'info, MakerControl>>,
+
+    #[account(
+        mut,
+        seeds = [b"risk_ledger", user.key().as_ref()],
+        bump = risk_ledger.bump,
+    )]
+    pub risk_ledger: Account<'info, RiskLedger>,
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
 pub struct JitParams {
     pub taker_order_id: u32,
     pub max_position: i64,
@@ -448,6 +1476,31 @@ pub struct JitParams {
     pub ask: i64,
     pub price_type: PriceType,
     pub post_only: Option<PostOnlyParam>,
+    pub max_fill_size: Option<u64>,
+    pub min_fill_size: Option<u64>,
+    pub price_ladder: Vec<PriceTier>,
+    /// price shift per unit of maker position, applied against the position
+    pub skew_per_base: Option<i64>,
+    /// max oracle confidence interval as bps of the oracle price
+    pub max_oracle_confidence_bps: Option<u64>,
+    /// max slots since the oracle was last updated
+    pub max_oracle_delay_slots: Option<u64>,
+    /// last slot the params can be used in
+    pub valid_until_slot: Option<u64>,
+    /// last unix timestamp the params can be used at
+    pub max_ts: Option<i64>,
+    /// oracle price the params were priced against
+    pub expected_oracle_price: Option<i64>,
+    /// max difference between the oracle price and expected oracle price as bps
+    pub max_oracle_deviation_bps: Option<u64>,
+    /// min distance between the final maker price and the oracle price as bps
+    pub min_edge_bps: Option<u64>,
+    /// treat bid/ask as prices net of the maker rebate
+    pub fee_aware: bool,
+    /// whether the taker order can be partially filled or must be filled in full
+    pub fill_mode: FillMode,
+    /// only fill taker orders that reduce the maker position
+    pub reduce_only: bool,
 }
 
 impl Default for JitParams {
@@ -460,29 +1513,53 @@ impl Default for JitParams {
             ask: 0,
             price_type: PriceType::Limit,
             post_only: None,
+            max_fill_size: None,
+            min_fill_size: None,
+            price_ladder: vec![],
+            skew_per_base: None,
+            max_oracle_confidence_bps: None,
+            max_oracle_delay_slots: None,
+            valid_until_slot: None,
+            max_ts: None,
+            expected_oracle_price: None,
+            max_oracle_deviation_bps: None,
+            min_edge_bps: None,
+            fee_aware: false,
+            fill_mode: FillMode::Partial,
+            reduce_only: false,
         }
     }
 }
 
 impl JitParams {
     pub fn get_worst_price(
-        self,
-        oracle_price: i64,
+        &self,
+        reference_price: i64,
         taker_direction: PositionDirection,
+        taker_base_asset_amount_unfilled: u64,
     ) -> DriftResult<u64> {
-        match (taker_direction, self.price_type) {
-            (PositionDirection::Long, PriceType::Limit) => Ok(self.ask.unsigned_abs()),
-            (PositionDirection::Short, PriceType::Limit) => Ok(self.bid.unsigned_abs()),
-            (PositionDirection::Long, PriceType::Oracle) => {
-                Ok(oracle_price.safe_add(self.ask)?.unsigned_abs())
-            }
-            (PositionDirection::Short, PriceType::Oracle) => {
-                Ok(oracle_price.safe_add(self.bid)?.unsigned_abs())
-            }
-        }
+        let (bid, ask) = self.get_bid_ask(taker_base_asset_amount_unfilled);
+        get_worst_price(self.price_type, bid, ask, reference_price, taker_direction)
+    }
+
+    /// Returns the bid and ask of the largest tier the taker's unfilled size reaches
+    fn get_bid_ask(&self, taker_base_asset_amount_unfilled: u64) -> (i64, i64) {
+        self.price_ladder
+            .iter()
+            .filter(|tier| taker_base_asset_amount_unfilled >= tier.size_threshold)
+            .max_by_key(|tier| tier.size_threshold)
+            .map_or((self.bid, self.ask), |tier| (tier.bid, tier.ask))
     }
 }
 
+#[derive(Debug, Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
+pub struct PriceTier {
+    /// min taker unfilled base asset amount for the tier to apply
+    pub size_threshold: u64,
+    pub bid: i64,
+    pub ask: i64,
+}
+
 #[derive(Debug, Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
 pub struct JitSignedMsgParams {
     pub signed_msg_order_uuid: [u8; 8],
@@ -492,6 +1569,20 @@ pub struct JitSignedMsgParams {
     pub ask: i64,
     pub price_type: PriceType,
     pub post_only: Option<PostOnlyParam>,
+    /// max oracle confidence interval as bps of the oracle price
+    pub max_oracle_confidence_bps: Option<u64>,
+    /// max slots since the oracle was last updated
+    pub max_oracle_delay_slots: Option<u64>,
+    /// last slot the params can be used in
+    pub valid_until_slot: Option<u64>,
+    /// last unix timestamp the params can be used at
+    pub max_ts: Option<i64>,
+    /// oracle price the params were priced against
+    pub expected_oracle_price: Option<i64>,
+    /// max difference between the oracle price and expected oracle price as bps
+    pub max_oracle_deviation_bps: Option<u64>,
+    /// only fill taker orders that reduce the maker position
+    pub reduce_only: bool,
 }
 
 impl Default for JitSignedMsgParams {
@@ -504,6 +1595,13 @@ impl Default for JitSignedMsgParams {
             ask: 0,
             price_type: PriceType::Limit,
             post_only: None,
+            max_oracle_confidence_bps: None,
+            max_oracle_delay_slots: None,
+            valid_until_slot: None,
+            max_ts: None,
+            expected_oracle_price: None,
+            max_oracle_deviation_bps: None,
+            reduce_only: false,
         }
     }
 }
@@ -511,22 +1609,111 @@ impl Default for JitSignedMsgParams {
 impl JitSignedMsgParams {
     pub fn get_worst_price(
         self,
-        oracle_price: i64,
+        reference_price: i64,
         taker_direction: PositionDirection,
     ) -> DriftResult<u64> {
-        match (taker_direction, self.price_type) {
-            (PositionDirection::Long, PriceType::Limit) => Ok(self.ask.unsigned_abs()),
-            (PositionDirection::Short, PriceType::Limit) => Ok(self.bid.unsigned_abs()),
-            (PositionDirection::Long, PriceType::Oracle) => {
-                Ok(oracle_price.safe_add(self.ask)?.unsigned_abs())
-            }
-            (PositionDirection::Short, PriceType::Oracle) => {
-                Ok(oracle_price.safe_add(self.bid)?.unsigned_abs())
-            }
+        get_worst_price(
+            self.price_type,
+            self.bid,
+            self.ask,
+            reference_price,
+            taker_direction,
+        )
+    }
+}
+
+fn get_worst_price(
+    price_type: PriceType,
+    bid: i64,
+    ask: i64,
+    reference_price: i64,
+    taker_direction: PositionDirection,
+) -> DriftResult<u64> {
+    let price = match taker_direction {
+        PositionDirection::Long => ask,
+        PositionDirection::Short => bid,
+    };
+
+    match price_type {
+        PriceType::Limit => Ok(price.unsigned_abs()),
+        PriceType::Oracle | PriceType::Amm | PriceType::OracleTwap => {
+            Ok(reference_price.safe_add(price)?.unsigned_abs())
         }
+        PriceType::OracleBps => {
+            let offset = reference_price.safe_mul(price)?.safe_div(BPS_PRECISION)?;
+            Ok(reference_price.safe_add(offset)?.unsigned_abs())
+        }
+    }
+}
+
+/// Set as return data by jit and jit_signed_msg
+#[derive(Debug, Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
+pub struct JitFillSummary {
+    /// change in the maker perp base asset amount or spot token amount
+    pub base_asset_amount_filled: u64,
+    /// average fill price from the change in the maker quote, net of fees
+    pub fill_price: u64,
+    /// maker perp base asset amount or signed spot token amount after the fill
+    pub maker_position: i64,
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
+pub struct JitBatchEntry {
+    /// index of the taker user in remaining accounts, the taker user stats must follow it
+    pub taker_index: u8,
+    pub params: JitParams,
+    /// index of the first account in remaining accounts forwarded to drift after the market
+    /// accounts, e.g. the referrer and referrer stats, spot vaults or builder escrow
+    pub extra_accounts_index: u8,
+    /// number of extra accounts forwarded to drift for this entry
+    pub extra_accounts_len: u8,
+}
+
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
+pub struct JitBatchParams {
+    pub entries: Vec<JitBatchEntry>,
+    /// skip entries that fail the checks before the drift cpi or don't fill instead of failing
+    /// the whole batch. an error from the drift cpi or after it, e.g. a partially filled fill or
+    /// kill entry, still fails the tx
+    pub best_effort: bool,
+}
+
+/// Returns the maker size clamped so the fill can't flip the maker position
+fn check_reduce_only(
+    maker_direction: PositionDirection,
+    maker_existing_position: i64,
+    maker_base_asset_amount: u64,
+    min_order_size: u64,
+) -> Result<u64> {
+    let reduces_position = match maker_direction {
+        PositionDirection::Long => maker_existing_position < 0,
+        PositionDirection::Short => maker_existing_position > 0,
+    };
+
+    if !reduces_position {
+        msg!(
+            "reduce only maker direction {:?} with existing position {}",
+            maker_direction,
+            maker_existing_position
+        );
+        return Err(ErrorCode::ReduceOnlyIncreasesPosition.into());
+    }
+
+    let size = maker_base_asset_amount.min(maker_existing_position.unsigned_abs());
+
+    if size < min_order_size {
+        msg!(
+            "reduce only size {} < min order size {}",
+            size,
+            min_order_size
+        );
+        return Err(ErrorCode::FillSizeBelowMinimum.into());
     }
+
+    Ok(size)
 }
 
+#[allow(clippy::too_many_arguments)]
 fn check_position_limits(
     max_position: i64,
     min_position: i64,
@@ -534,8 +1721,10 @@ fn check_position_limits(
     taker_base_asset_amount_unfilled: u64,
     maker_existing_position: i64,
     min_order_size: u64,
+    max_fill_size: Option<u64>,
+    min_fill_size: Option<u64>,
 ) -> Result<u64> {
-    if maker_direction == PositionDirection::Long {
+    let size = if maker_direction == PositionDirection::Long {
         let size = max_position.safe_sub(maker_existing_position)?;
 
         if size <= min_order_size.cast()? {
@@ -548,7 +1737,7 @@ fn check_position_limits(
             return Err(ErrorCode::PositionLimitBreached.into());
         }
 
-        Ok(size.unsigned_abs().min(taker_base_asset_amount_unfilled))
+        size.unsigned_abs().min(taker_base_asset_amount_unfilled)
     } else {
         let size = maker_existing_position.safe_sub(min_position)?;
 
@@ -562,12 +1751,55 @@ fn check_position_limits(
             return Err(ErrorCode::PositionLimitBreached.into());
         }
 
-        Ok(size.unsigned_abs().min(taker_base_asset_amount_unfilled))
+        size.unsigned_abs().min(taker_base_asset_amount_unfilled)
+    };
+
+    let size = size.min(max_fill_size.unwrap_or(u64::MAX));
+
+    if let Some(min_fill_size) = min_fill_size {
+        if size < min_fill_size {
+            msg!("fill size {} < min fill size {}", size, min_fill_size);
+            return Err(ErrorCode::FillSizeBelowMinimum.into());
+        }
     }
+
+    Ok(size)
 }
 
 fn place_and_make<'info>(
-    ctx: &Context<'_, '_, '_, 'info, Jit<'info>>,
+    accounts: &Jit<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    taker_order_id: u32,
+    order_params: OrderParams,
+) -> Result<()> {
+    let drift_program = accounts.drift_program.to_account_info().clone();
+    let cpi_accounts = PlaceAndMake {
+        state: accounts.state.to_account_info().clone(),
+        user: accounts.user.to_account_info().clone(),
+        user_stats: accounts.user_stats.to_account_info().clone(),
+        authority: accounts.authority.to_account_info().clone(),
+        taker: accounts.taker.to_account_info().clone(),
+        taker_stats: accounts.taker_stats.to_account_info().clone(),
+    };
+
+    let cpi_context = CpiContext::new(drift_program, cpi_accounts)
+        .with_remaining_accounts(remaining_accounts.into());
+
+    if order_params.market_type == DriftMarketType::Perp {
+        drift::cpi::place_and_make_perp_order(cpi_context, order_params, taker_order_id)?;
+    } else {
+        drift::cpi::place_and_make_spot_order(cpi_context, order_params, taker_order_id, None)?;
+    }
+
+    Ok(())
+}
+
+fn place_and_make_batch<'info>(
+    ctx: &Context<'_, '_, '_, 'info, JitBatch<'info>>,
+    taker: &AccountInfo<'info>,
+    taker_stats: &AccountInfo<'info>,
+    market_accounts: &[AccountInfo<'info>],
+    extra_accounts: &[AccountInfo<'info>],
     taker_order_id: u32,
     order_params: OrderParams,
 ) -> Result<()> {
@@ -577,12 +1809,17 @@ fn place_and_make<'info>(
         user: ctx.accounts.user.to_account_info().clone(),
         user_stats: ctx.accounts.user_stats.to_account_info().clone(),
         authority: ctx.accounts.authority.to_account_info().clone(),
-        taker: ctx.accounts.taker.to_account_info().clone(),
-        taker_stats: ctx.accounts.taker_stats.to_account_info().clone(),
+        taker: taker.clone(),
+        taker_stats: taker_stats.clone(),
     };
 
-    let cpi_context = CpiContext::new(drift_program, cpi_accounts)
-        .with_remaining_accounts(ctx.remaining_accounts.into());
+    let cpi_context = CpiContext::new(drift_program, cpi_accounts).with_remaining_accounts(
+        market_accounts
+            .iter()
+            .chain(extra_accounts.iter())
+            .cloned()
+            .collect(),
+    );
 
     if order_params.market_type == DriftMarketType::Perp {
         drift::cpi::place_and_make_perp_order(cpi_context, order_params, taker_order_id)?;
@@ -596,12 +1833,39 @@ fn place_and_make<'info>(
 fn place_and_make_signed_msg<'info>(
     ctx: &Context<'_, '_, '_, 'info, JitSignedMsg<'info>>,
     order_params: OrderParams,
+    taker_order_id: u32,
     signed_msg_order_uuid: [u8; 8],
 ) -> Result<()> {
     let drift_program = ctx.accounts.drift_program.to_account_info();
     let state = ctx.accounts.state.to_account_info();
     let taker = ctx.accounts.taker.to_account_info();
     let taker_stats = ctx.accounts.taker_stats.to_account_info();
+
+    // drift only has a signed msg fill for perps, spot signed msg orders are already on the
+    // taker account so they are filled by order id
+    if order_params.market_type == DriftMarketType::Spot {
+        let cpi_accounts_place_and_make = PlaceAndMake {
+            state,
+            user: ctx.accounts.user.to_account_info().clone(),
+            user_stats: ctx.accounts.user_stats.to_account_info().clone(),
+            authority: ctx.accounts.authority.to_account_info().clone(),
+            taker,
+            taker_stats,
+        };
+
+        let cpi_context_place_and_make =
+            CpiContext::new(drift_program, cpi_accounts_place_and_make)
+                .with_remaining_accounts(ctx.remaining_accounts.into());
+
+        drift::cpi::place_and_make_spot_order(
+            cpi_context_place_and_make,
+            order_params,
+            taker_order_id,
+            None,
+        )?;
+        return Ok(());
+    }
+
     let taker_signed_msg_user_orders = ctx.accounts.taker_signed_msg_user_orders.to_account_info();
 
     let cpi_accounts_place_and_make = PlaceAndMakeSignedMsg {
@@ -628,6 +1892,9 @@ fn place_and_make_signed_msg<'info>(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use drift::math::constants::{
+        BASE_PRECISION_I64, BASE_PRECISION_U64, PRICE_PRECISION_I64, PRICE_PRECISION_U64,
+    };
 
     #[test]
     fn test_check_position_limits() {
@@ -642,6 +1909,8 @@ mod tests {
             10,
             40,
             0,
+            None,
+            None,
         );
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), 10);
@@ -652,6 +1921,8 @@ mod tests {
             10,
             -40,
             0,
+            None,
+            None,
         );
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), 10);
@@ -664,6 +1935,8 @@ mod tests {
             100,
             40,
             0,
+            None,
+            None,
         );
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), 60);
@@ -674,6 +1947,8 @@ mod tests {
             100,
             -40,
             0,
+            None,
+            None,
         );
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), 60);
@@ -686,6 +1961,8 @@ mod tests {
             10,
             -40,
             0,
+            None,
+            None,
         );
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), 10);
@@ -696,6 +1973,8 @@ mod tests {
             10,
             40,
             0,
+            None,
+            None,
         );
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), 10);
@@ -708,6 +1987,8 @@ mod tests {
             200,
             -40,
             0,
+            None,
+            None,
         );
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), 140);
@@ -718,6 +1999,8 @@ mod tests {
             200,
             40,
             0,
+            None,
+            None,
         );
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), 140);
@@ -730,6 +2013,8 @@ mod tests {
             200,
             -150,
             0,
+            None,
+            None,
         );
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), 200);
@@ -740,6 +2025,8 @@ mod tests {
             200,
             150,
             0,
+            None,
+            None,
         );
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), 200);
@@ -752,6 +2039,8 @@ mod tests {
             200,
             150,
             0,
+            None,
+            None,
         );
         assert!(result.is_err());
         let result = check_position_limits(
@@ -761,7 +2050,394 @@ mod tests {
             200,
             -150,
             0,
+            None,
+            None,
         );
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_get_worst_price_price_ladder() {
+        let params = JitParams {
+            bid: -10,
+            ask: 10,
+            price_type: PriceType::Oracle,
+            price_ladder: vec![
+                PriceTier {
+                    size_threshold: 1000,
+                    bid: -50,
+                    ask: 50,
+                },
+                PriceTier {
+                    size_threshold: 100,
+                    bid: -20,
+                    ask: 20,
+                },
+            ],
+            ..JitParams::default()
+        };
+
+        // below every tier uses bid/ask
+        let result = params.get_worst_price(1000, PositionDirection::Long, 10);
+        assert_eq!(result.unwrap(), 1010);
+        let result = params.get_worst_price(1000, PositionDirection::Short, 10);
+        assert_eq!(result.unwrap(), 990);
+
+        // uses the largest tier reached
+        let result = params.get_worst_price(1000, PositionDirection::Long, 100);
+        assert_eq!(result.unwrap(), 1020);
+        let result = params.get_worst_price(1000, PositionDirection::Short, 999);
+        assert_eq!(result.unwrap(), 980);
+        let result = params.get_worst_price(1000, PositionDirection::Long, 5000);
+        assert_eq!(result.unwrap(), 1050);
+        let result = params.get_worst_price(1000, PositionDirection::Short, 5000);
+        assert_eq!(result.unwrap(), 950);
+    }
+
+    #[test]
+    fn test_get_worst_price_oracle_bps() {
+        let params = JitSignedMsgParams {
+            bid: -50,
+            ask: 25,
+            price_type: PriceType::OracleBps,
+            ..JitSignedMsgParams::default()
+        };
+
+        let oracle_price = 100 * PRICE_PRECISION_I64;
+
+        let result = params.get_worst_price(oracle_price, PositionDirection::Long);
+        assert_eq!(result.unwrap(), 100_250_000);
+        let result = params.get_worst_price(oracle_price, PositionDirection::Short);
+        assert_eq!(result.unwrap(), 99_500_000);
+
+        let params = JitParams {
+            bid: -50,
+            ask: 25,
+            price_type: PriceType::OracleBps,
+            ..JitParams::default()
+        };
+
+        let result = params.get_worst_price(oracle_price, PositionDirection::Long, 0);
+        assert_eq!(result.unwrap(), 100_250_000);
+        let result = params.get_worst_price(oracle_price, PositionDirection::Short, 0);
+        assert_eq!(result.unwrap(), 99_500_000);
+    }
+
+    #[test]
+    fn test_get_worst_price_reference_price() {
+        let reference_price = 100 * PRICE_PRECISION_I64;
+
+        for price_type in [PriceType::Amm, PriceType::OracleTwap] {
+            let params = JitSignedMsgParams {
+                bid: -PRICE_PRECISION_I64,
+                ask: PRICE_PRECISION_I64,
+                price_type,
+                ..JitSignedMsgParams::default()
+            };
+
+            let result = params.get_worst_price(reference_price, PositionDirection::Long);
+            assert_eq!(result.unwrap(), 101 * PRICE_PRECISION_U64);
+            let result = params.get_worst_price(reference_price, PositionDirection::Short);
+            assert_eq!(result.unwrap(), 99 * PRICE_PRECISION_U64);
+        }
+    }
+
+    #[test]
+    fn test_apply_skew() {
+        let worst_price = 100 * PRICE_PRECISION_U64;
+        let skew_per_base = PRICE_PRECISION_I64 / 10;
+
+        // long 2 shifts quotes down
+        let result = apply_skew(
+            worst_price,
+            2 * BASE_PRECISION_I64,
+            skew_per_base,
+            BASE_PRECISION,
+        );
+        assert_eq!(result.unwrap(), 99_800_000);
+
+        // short 2 shifts quotes up
+        let result = apply_skew(
+            worst_price,
+            -2 * BASE_PRECISION_I64,
+            skew_per_base,
+            BASE_PRECISION,
+        );
+        assert_eq!(result.unwrap(), 100_200_000);
+
+        // flat is unchanged
+        let result = apply_skew(worst_price, 0, skew_per_base, BASE_PRECISION);
+        assert_eq!(result.unwrap(), worst_price);
+
+        // spot token precision
+        let result = apply_skew(worst_price, 2_000_000, skew_per_base, 1_000_000);
+        assert_eq!(result.unwrap(), 99_800_000);
+    }
+
+    #[test]
+    fn test_check_oracle_guards() {
+        let oracle_price_data = OraclePriceData {
+            price: 100 * PRICE_PRECISION_I64,
+            confidence: 100 * PRICE_PRECISION_U64 / 1000, // 10 bps
+            delay: 5,
+            ..OraclePriceData::default()
+        };
+
+        assert!(check_oracle_guards(&oracle_price_data, None, None).is_ok());
+        assert!(check_oracle_guards(&oracle_price_data, Some(10), Some(5)).is_ok());
+
+        let result = check_oracle_guards(&oracle_price_data, Some(9), None);
+        assert_eq!(result, Err(ErrorCode::OracleConfidenceTooWide.into()));
+
+        let result = check_oracle_guards(&oracle_price_data, None, Some(4));
+        assert_eq!(result, Err(ErrorCode::OracleTooStale.into()));
+    }
+
+    #[test]
+    fn test_check_expiry() {
+        assert!(check_expiry(None, None, 100, 1_000).is_ok());
+        assert!(check_expiry(Some(100), Some(1_000), 100, 1_000).is_ok());
+
+        let result = check_expiry(Some(99), None, 100, 1_000);
+        assert_eq!(result, Err(ErrorCode::Expired.into()));
+
+        let result = check_expiry(None, Some(999), 100, 1_000);
+        assert_eq!(result, Err(ErrorCode::Expired.into()));
+    }
+
+    #[test]
+    fn test_check_oracle_deviation() {
+        let expected_oracle_price = 100 * PRICE_PRECISION_I64;
+
+        assert!(check_oracle_deviation(101 * PRICE_PRECISION_I64, None, None).is_ok());
+
+        let result = check_oracle_deviation(101 * PRICE_PRECISION_I64, None, Some(50));
+        assert_eq!(result, Err(ErrorCode::InvalidOracleDeviationParams.into()));
+
+        let result =
+            check_oracle_deviation(101 * PRICE_PRECISION_I64, Some(expected_oracle_price), None);
+        assert_eq!(result, Err(ErrorCode::InvalidOracleDeviationParams.into()));
+
+        assert!(check_oracle_deviation(99_500_000, Some(expected_oracle_price), Some(50)).is_ok());
+
+        let result = check_oracle_deviation(100_510_000, Some(expected_oracle_price), Some(50));
+        assert_eq!(result, Err(ErrorCode::OracleDeviationTooLarge.into()));
+
+        let result = check_oracle_deviation(
+            99 * PRICE_PRECISION_I64,
+            Some(expected_oracle_price),
+            Some(50),
+        );
+        assert_eq!(result, Err(ErrorCode::OracleDeviationTooLarge.into()));
+    }
+
+    #[test]
+    fn test_check_min_edge() {
+        let oracle_price = 100 * PRICE_PRECISION_I64;
+
+        // 10 bps edge is 0.1
+        assert!(check_min_edge(99_900_000, oracle_price, PositionDirection::Long, 10).is_ok());
+        assert!(check_min_edge(100_100_000, oracle_price, PositionDirection::Short, 10).is_ok());
+
+        let result = check_min_edge(99_900_001, oracle_price, PositionDirection::Long, 10);
+        assert_eq!(result, Err(ErrorCode::InsufficientEdge.into()));
+
+        let result = check_min_edge(100_099_999, oracle_price, PositionDirection::Short, 10);
+        assert_eq!(result, Err(ErrorCode::InsufficientEdge.into()));
+    }
+
+    #[test]
+    fn test_calculate_fee_adjusted_price() {
+        let fee_tier = FeeTier {
+            maker_rebate_numerator: 2,
+            maker_rebate_denominator: 10_000,
+            ..FeeTier::default()
+        };
+
+        // 2 bps rebate lets the maker bid higher and ask lower for the same net price
+        let result =
+            calculate_fee_adjusted_price(99_980_000, PositionDirection::Long, &fee_tier, 0);
+        assert_eq!(result.unwrap(), 100 * PRICE_PRECISION_U64);
+        let result =
+            calculate_fee_adjusted_price(100_020_000, PositionDirection::Short, &fee_tier, 0);
+        assert_eq!(result.unwrap(), 100 * PRICE_PRECISION_U64);
+
+        // -100 fee adjustment removes the rebate
+        let result = calculate_fee_adjusted_price(
+            100 * PRICE_PRECISION_U64,
+            PositionDirection::Long,
+            &fee_tier,
+            -100,
+        );
+        assert_eq!(result.unwrap(), 100 * PRICE_PRECISION_U64);
+    }
+
+    #[test]
+    fn test_check_reduce_only() {
+        // short position, maker buying reduces and is clamped to the position
+        let result = check_reduce_only(
+            PositionDirection::Long,
+            -BASE_PRECISION_I64,
+            2 * BASE_PRECISION_U64,
+            BASE_PRECISION_U64 / 10,
+        );
+        assert_eq!(result.unwrap(), BASE_PRECISION_U64);
+
+        // long position, maker selling less than the position is unchanged
+        let result = check_reduce_only(
+            PositionDirection::Short,
+            2 * BASE_PRECISION_I64,
+            BASE_PRECISION_U64,
+            BASE_PRECISION_U64 / 10,
+        );
+        assert_eq!(result.unwrap(), BASE_PRECISION_U64);
+
+        // position left to reduce is below the min order size
+        let result = check_reduce_only(
+            PositionDirection::Short,
+            BASE_PRECISION_I64 / 20,
+            BASE_PRECISION_U64,
+            BASE_PRECISION_U64 / 10,
+        );
+        assert_eq!(result, Err(ErrorCode::FillSizeBelowMinimum.into()));
+
+        let result = check_reduce_only(
+            PositionDirection::Long,
+            0,
+            BASE_PRECISION_U64,
+            BASE_PRECISION_U64 / 10,
+        );
+        assert_eq!(result, Err(ErrorCode::ReduceOnlyIncreasesPosition.into()));
+
+        let result = check_reduce_only(
+            PositionDirection::Short,
+            -BASE_PRECISION_I64,
+            BASE_PRECISION_U64,
+            BASE_PRECISION_U64 / 10,
+        );
+        assert_eq!(result, Err(ErrorCode::ReduceOnlyIncreasesPosition.into()));
+    }
+
+    #[test]
+    fn test_calculate_fill_summary() {
+        // perp maker buys 2 at 100 with a 0.2 bps rebate on the 200 notional
+        let summary = calculate_fill_summary(
+            (0, 0),
+            (2 * BASE_PRECISION_I64 as i128, -199_996_000),
+            BASE_PRECISION,
+        )
+        .unwrap();
+        assert_eq!(
+            summary,
+            JitFillSummary {
+                base_asset_amount_filled: 2 * BASE_PRECISION_U64,
+                fill_price: 99_998_000,
+                maker_position: 2 * BASE_PRECISION_I64,
+            }
+        );
+
+        // spot maker with 6 decimals sells 1 at 100
+        let summary =
+            calculate_fill_summary((5_000_000, 0), (4_000_000, 100_000_000), 1_000_000).unwrap();
+        assert_eq!(
+            summary,
+            JitFillSummary {
+                base_asset_amount_filled: 1_000_000,
+                fill_price: 100 * PRICE_PRECISION_U64,
+                maker_position: 4_000_000,
+            }
+        );
+
+        // no change in the maker position
+        let summary = calculate_fill_summary(
+            (BASE_PRECISION_I64 as i128, 0),
+            (BASE_PRECISION_I64 as i128, 0),
+            BASE_PRECISION,
+        )
+        .unwrap();
+        assert_eq!(summary.base_asset_amount_filled, 0);
+        assert_eq!(summary.fill_price, 0);
+    }
+
+    #[test]
+    fn test_check_position_limits_fill_size() {
+        let max_position: i64 = 100;
+        let min_position: i64 = -100;
+
+        // clamped to max fill size
+        let result = check_position_limits(
+            max_position,
+            min_position,
+            PositionDirection::Long,
+            50,
+            0,
+            0,
+            Some(20),
+            None,
+        );
+        assert_eq!(result.unwrap(), 20);
+        let result = check_position_limits(
+            max_position,
+            min_position,
+            PositionDirection::Short,
+            50,
+            0,
+            0,
+            Some(20),
+            None,
+        );
+        assert_eq!(result.unwrap(), 20);
+
+        // position limit smaller than max fill size
+        let result = check_position_limits(
+            max_position,
+            min_position,
+            PositionDirection::Long,
+            50,
+            90,
+            0,
+            Some(20),
+            None,
+        );
+        assert_eq!(result.unwrap(), 10);
+
+        // above min fill size
+        let result = check_position_limits(
+            max_position,
+            min_position,
+            PositionDirection::Long,
+            50,
+            0,
+            0,
+            Some(20),
+            Some(20),
+        );
+        assert_eq!(result.unwrap(), 20);
+
+        // taker order below min fill size
+        let result = check_position_limits(
+            max_position,
+            min_position,
+            PositionDirection::Short,
+            5,
+            0,
+            0,
+            None,
+            Some(10),
+        );
+        assert_eq!(result, Err(ErrorCode::FillSizeBelowMinimum.into()));
+
+        // position limit clamps below min fill size
+        let result = check_position_limits(
+            max_position,
+            min_position,
+            PositionDirection::Long,
+            50,
+            95,
+            0,
+            None,
+            Some(10),
+        );
+        assert_eq!(result, Err(ErrorCode::FillSizeBelowMinimum.into()));
+    }
 }
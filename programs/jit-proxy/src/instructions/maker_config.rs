@@ -0,0 +1,176 @@
+use anchor_lang::prelude::*;
+use drift::state::user::User;
+
+use super::jit::{jit_inner, Jit, JitParams};
+use crate::error::ErrorCode;
+use crate::state::{MakerConfig, MarketType, PostOnlyParam, PriceType};
+
+pub fn init_maker_config(
+    ctx: Context<InitMakerConfig>,
+    market_type: MarketType,
+    market_index: u16,
+    params: MakerConfigParams,
+) -> Result<()> {
+    let maker_config = &mut ctx.accounts.maker_config;
+    maker_config.user = ctx.accounts.user.key();
+    maker_config.market_type = market_type;
+    maker_config.market_index = market_index;
+    maker_config.bump = ctx.bumps.maker_config;
+    set_params(maker_config, params);
+
+    Ok(())
+}
+
+pub fn update_maker_config(
+    ctx: Context<UpdateMakerConfig>,
+    params: MakerConfigParams,
+) -> Result<()> {
+    set_params(&mut ctx.accounts.maker_config, params);
+
+    Ok(())
+}
+
+pub fn close_maker_config(_ctx: Context<CloseMakerConfig>) -> Result<()> {
+    Ok(())
+}
+
+pub fn jit_with_config<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, JitWithConfig<'info>>,
+    taker_order_id: u32,
+) -> Result<()> {
+    let maker_config = &ctx.accounts.maker_config;
+
+    let taker = ctx.accounts.jit.taker.load()?;
+    let taker_order = taker
+        .get_order(taker_order_id)
+        .ok_or(ErrorCode::TakerOrderNotFound)?;
+
+    if taker_order.market_type != maker_config.market_type.to_drift_param()
+        || taker_order.market_index != maker_config.market_index
+    {
+        msg!(
+            "taker order market {:?} {} != maker config market {:?} {}",
+            taker_order.market_type,
+            taker_order.market_index,
+            maker_config.market_type,
+            maker_config.market_index
+        );
+        return Err(ErrorCode::MakerConfigMarketMismatch.into());
+    }
+
+    drop(taker);
+
+    let params = JitParams {
+        taker_order_id,
+        max_position: maker_config.max_position,
+        min_position: maker_config.min_position,
+        bid: maker_config.bid,
+        ask: maker_config.ask,
+        price_type: maker_config.price_type,
+        post_only: maker_config.post_only.map(|p| p.to_drift_param()),
+        ..JitParams::default()
+    };
+
+    jit_inner(&mut ctx.accounts.jit, ctx.remaining_accounts, params)
+}
+
+fn set_params(maker_config: &mut MakerConfig, params: MakerConfigParams) {
+    maker_config.max_position = params.max_position;
+    maker_config.min_position = params.min_position;
+    maker_config.bid = params.bid;
+    maker_config.ask = params.ask;
+    maker_config.price_type = params.price_type;
+    maker_config.post_only = params.post_only;
+}
+
+#[derive(Accounts)]
+#[instruction(market_type: MarketType, market_index: u16)]
+pub struct InitMakerConfig<'info> {
+    #[account(
+        constraint = user.load()?.authority == authority.key() @ ErrorCode::InvalidAuthority
+    )]
+    pub user: AccountLoader<'info, User>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        init,
+        seeds = [
+            b"maker_config",
+            user.key().as_ref(),
+            &[market_type as u8],
+            &market_index.to_le_bytes(),
+        ],
+        bump,
+        payer = authority,
+        space = MakerConfig::SIZE,
+    )]
+    pub maker_config: Account<'info, MakerConfig>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateMakerConfig<'info> {
+    #[account(
+        constraint = user.load()?.authority == authority.key() @ ErrorCode::InvalidAuthority
+    )]
+    pub user: AccountLoader<'info, User>,
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"maker_config",
+            user.key().as_ref(),
+            &[maker_config.market_type as u8],
+            &maker_config.market_index.to_le_bytes(),
+        ],
+        bump = maker_config.bump,
+    )]
+    pub maker_config: Account<'info, MakerConfig>,
+}
+
+#[derive(Accounts)]
+pub struct CloseMakerConfig<'info> {
+    #[account(
+        constraint = user.load()?.authority == authority.key() @ ErrorCode::InvalidAuthority
+    )]
+    pub user: AccountLoader<'info, User>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"maker_config",
+            user.key().as_ref(),
+            &[maker_config.market_type as u8],
+            &maker_config.market_index.to_le_bytes(),
+        ],
+        bump = maker_config.bump,
+        close = authority,
+    )]
+    pub maker_config: Account<'info, MakerConfig>,
+}
+
+#[derive(Accounts)]
+pub struct JitWithConfig<'info> {
+    pub jit: Jit<'info>,
+    #[account(
+        seeds = [
+            b"maker_config",
+            jit.user.key().as_ref(),
+            &[maker_config.market_type as u8],
+            &maker_config.market_index.to_le_bytes(),
+        ],
+        bump = maker_config.bump,
+    )]
+    pub maker_config: Account<'info, MakerConfig>,
+}
+
+#[derive(Debug, Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
+pub struct MakerConfigParams {
+    pub max_position: i64,
+    pub min_position: i64,
+    pub bid: i64,
+    pub ask: i64,
+    pub price_type: PriceType,
+    pub post_only: Option<PostOnlyParam>,
+}
@@ -0,0 +1,146 @@
+use anchor_lang::prelude::*;
+use drift::state::user::{MarketType as DriftMarketType, User};
+
+use crate::error::ErrorCode;
+use crate::state::MakerControl;
+
+pub fn init_maker_control(ctx: Context<InitMakerControl>) -> Result<()> {
+    let maker_control = &mut ctx.accounts.maker_control;
+    maker_control.user = ctx.accounts.user.key();
+    maker_control.bump = ctx.bumps.maker_control;
+
+    Ok(())
+}
+
+pub fn update_maker_control(
+    ctx: Context<UpdateMakerControl>,
+    params: MakerControlParams,
+) -> Result<()> {
+    let maker_control = &mut ctx.accounts.maker_control;
+    maker_control.paused = params.paused;
+    maker_control.paused_perp_markets = get_paused_markets(&params.paused_perp_markets)?;
+    maker_control.paused_spot_markets = get_paused_markets(&params.paused_spot_markets)?;
+
+    msg!(
+        "maker control paused {} perp markets {:#x} spot markets {:#x}",
+        maker_control.paused,
+        maker_control.paused_perp_markets,
+        maker_control.paused_spot_markets
+    );
+
+    Ok(())
+}
+
+/// Returns the pause bits for market_indexes, only markets below 128 can be paused individually
+fn get_paused_markets(market_indexes: &[u16]) -> Result<u128> {
+    let mut paused_markets: u128 = 0;
+    for &market_index in market_indexes {
+        if market_index >= 128 {
+            msg!("market {} can only be paused with paused", market_index);
+            return Err(ErrorCode::MarketIndexNotPausable.into());
+        }
+
+        paused_markets |= 1 << market_index;
+    }
+
+    Ok(paused_markets)
+}
+
+pub(crate) fn check_maker_control(
+    maker_control: &Option<Account<MakerControl>>,
+    market_type: DriftMarketType,
+    market_index: u16,
+) -> Result<()> {
+    if let Some(maker_control) = maker_control {
+        if maker_control.is_paused(market_type.into(), market_index) {
+            msg!("maker paused for market {:?} {}", market_type, market_index);
+            return Err(ErrorCode::Paused.into());
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitMakerControl<'info> {
+    #[account(
+        constraint = user.load()?.authority == authority.key() @ ErrorCode::InvalidAuthority
+    )]
+    pub user: AccountLoader<'info, User>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        init,
+        seeds = [b"maker_control", user.key().as_ref()],
+        bump,
+        payer = authority,
+        space = MakerControl::SIZE,
+    )]
+    pub maker_control: Account<'info, MakerControl>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateMakerControl<'info> {
+    #[account(
+        constraint = user.load()?.authority == authority.key() @ ErrorCode::InvalidAuthority
+    )]
+    pub user: AccountLoader<'info, User>,
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"maker_control", user.key().as_ref()],
+        bump = maker_control.bump,
+    )]
+    pub maker_control: Account<'info, MakerControl>,
+}
+
+#[derive(Debug, Clone, Default, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
+pub struct MakerControlParams {
+    pub paused: bool,
+    /// perp market indexes to pause, each must be below 128
+    pub paused_perp_markets: Vec<u16>,
+    /// spot market indexes to pause, each must be below 128
+    pub paused_spot_markets: Vec<u16>,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::error::ErrorCode;
+    use crate::state::{MakerControl, MarketType};
+    use anchor_lang::prelude::Pubkey;
+
+    #[test]
+    pub fn get_paused_markets() {
+        assert_eq!(super::get_paused_markets(&[]).unwrap(), 0);
+
+        let expected: u128 = 1 | (1 << 2) | (1 << 127);
+        assert_eq!(super::get_paused_markets(&[0, 2, 127]).unwrap(), expected);
+
+        assert_eq!(
+            super::get_paused_markets(&[1, 128]),
+            Err(ErrorCode::MarketIndexNotPausable.into())
+        );
+    }
+
+    #[test]
+    pub fn is_paused() {
+        let mut maker_control = MakerControl {
+            user: Pubkey::default(),
+            paused: false,
+            paused_perp_markets: 1 << 2,
+            paused_spot_markets: 1 << 127,
+            bump: 0,
+        };
+
+        assert!(maker_control.is_paused(MarketType::Perp, 2));
+        assert!(!maker_control.is_paused(MarketType::Spot, 2));
+        assert!(maker_control.is_paused(MarketType::Spot, 127));
+        assert!(!maker_control.is_paused(MarketType::Perp, 127));
+        assert!(!maker_control.is_paused(MarketType::Perp, 200));
+
+        maker_control.paused = true;
+        assert!(maker_control.is_paused(MarketType::Perp, 0));
+        assert!(maker_control.is_paused(MarketType::Spot, 200));
+    }
+}
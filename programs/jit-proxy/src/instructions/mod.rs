@@ -1,7 +1,17 @@
+mod arb_basis;
 mod arb_perp;
+mod arb_spot;
 mod check_order_constraints;
 mod jit;
+mod maker_config;
+mod maker_control;
+mod risk_ledger;
 
+pub use arb_basis::*;
 pub use arb_perp::*;
+pub use arb_spot::*;
 pub use check_order_constraints::*;
 pub use jit::*;
+pub use maker_config::*;
+pub use maker_control::*;
+pub use risk_ledger::*;
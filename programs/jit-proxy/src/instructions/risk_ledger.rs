@@ -0,0 +1,294 @@
+use anchor_lang::prelude::*;
+use drift::error::DriftResult;
+use drift::math::casting::Cast;
+use drift::math::constants::BASE_PRECISION;
+use drift::math::safe_math::SafeMath;
+use drift::state::spot_market_map::SpotMarketMap;
+use drift::state::user::{MarketType as DriftMarketType, User};
+
+use crate::error::ErrorCode;
+use crate::state::RiskLedger;
+
+pub fn init_risk_ledger(ctx: Context<InitRiskLedger>, params: RiskLedgerParams) -> Result<()> {
+    let risk_ledger = &mut ctx.accounts.risk_ledger;
+    risk_ledger.user = ctx.accounts.user.key();
+    risk_ledger.bump = ctx.bumps.risk_ledger;
+    risk_ledger.window_start_ts = Clock::get()?.unix_timestamp;
+    set_params(risk_ledger, params)
+}
+
+pub fn update_risk_ledger(ctx: Context<UpdateRiskLedger>, params: RiskLedgerParams) -> Result<()> {
+    set_params(&mut ctx.accounts.risk_ledger, params)
+}
+
+fn set_params(risk_ledger: &mut RiskLedger, params: RiskLedgerParams) -> Result<()> {
+    if params.window_duration <= 0 {
+        msg!(
+            "window duration {} must be positive",
+            params.window_duration
+        );
+        return Err(ErrorCode::InvalidWindowDuration.into());
+    }
+
+    risk_ledger.window_duration = params.window_duration;
+    risk_ledger.max_window_notional = params.max_window_notional;
+    risk_ledger.max_window_loss = params.max_window_loss;
+
+    Ok(())
+}
+
+/// Returns the perp base and quote asset amounts or the spot and quote spot token amounts
+pub(crate) fn get_base_and_quote_amounts(
+    user: &User,
+    market_type: DriftMarketType,
+    market_index: u16,
+    spot_market_map: &SpotMarketMap,
+) -> Result<(i128, i128)> {
+    if market_type == DriftMarketType::Perp {
+        Ok(user.get_perp_position(market_index).map_or((0, 0), |p| {
+            (p.base_asset_amount.cast(), p.quote_asset_amount.cast())
+        }))
+    } else {
+        let spot_market = spot_market_map.get_ref(&market_index)?;
+        let quote_spot_market = spot_market_map.get_quote_spot_market()?;
+
+        let base = user
+            .get_spot_position(market_index)
+            .map_or(Ok(0), |p| p.get_signed_token_amount(&spot_market))?;
+        let quote = user
+            .get_quote_spot_position()
+            .get_signed_token_amount(&quote_spot_market)?;
+
+        Ok((base, quote))
+    }
+}
+
+/// Returns the precision of the amounts returned by get_base_and_quote_amounts
+pub(crate) fn get_base_precision(
+    market_type: DriftMarketType,
+    market_index: u16,
+    spot_market_map: &SpotMarketMap,
+) -> Result<u128> {
+    if market_type == DriftMarketType::Perp {
+        Ok(BASE_PRECISION)
+    } else {
+        Ok(spot_market_map
+            .get_ref(&market_index)?
+            .get_precision()
+            .cast()?)
+    }
+}
+
+/// Records the change in the maker position between position_init and position_end and checks
+/// the limits
+pub(crate) fn record_fill(
+    risk_ledger: &mut RiskLedger,
+    position_init: (i128, i128),
+    position_end: (i128, i128),
+    base_precision: u128,
+    oracle_price: i64,
+) -> Result<()> {
+    let (base_init, quote_init) = position_init;
+    let (base_end, quote_end) = position_end;
+
+    let (notional, pnl) = calculate_notional_and_pnl(
+        base_end.safe_sub(base_init)?,
+        quote_end.safe_sub(quote_init)?,
+        oracle_price,
+        base_precision,
+    )?;
+
+    risk_ledger.record(notional, pnl)?;
+    msg!(
+        "risk ledger window notional {} window pnl {}",
+        risk_ledger.window_notional,
+        risk_ledger.window_pnl
+    );
+
+    risk_ledger.check_limits()
+}
+
+/// Returns the notional of a base amount marked at the oracle price in quote precision
+pub(crate) fn calculate_notional(
+    base_asset_amount: u128,
+    oracle_price: i64,
+    base_precision: u128,
+) -> DriftResult<u64> {
+    base_asset_amount
+        .safe_mul(oracle_price.unsigned_abs().cast()?)?
+        .safe_div(base_precision)?
+        .cast()
+}
+
+/// Returns the notional of the base change and the quote change plus the base change marked at
+/// the oracle price, both in quote precision
+fn calculate_notional_and_pnl(
+    base_delta: i128,
+    quote_delta: i128,
+    oracle_price: i64,
+    base_precision: u128,
+) -> DriftResult<(u64, i64)> {
+    let base_value = base_delta
+        .safe_mul(oracle_price.cast()?)?
+        .safe_div(base_precision.cast()?)?;
+
+    let notional = base_value.unsigned_abs().cast()?;
+    let pnl = quote_delta.safe_add(base_value)?.cast()?;
+
+    Ok((notional, pnl))
+}
+
+#[derive(Accounts)]
+pub struct InitRiskLedger<'info> {
+    #[account(
+        constraint = user.load()?.authority == authority.key() @ ErrorCode::InvalidAuthority
+    )]
+    pub user: AccountLoader<'info, User>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        init,
+        seeds = [b"risk_ledger", user.key().as_ref()],
+        bump,
+        payer = authority,
+        space = RiskLedger::SIZE,
+    )]
+    pub risk_ledger: Account<'info, RiskLedger>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateRiskLedger<'info> {
+    #[account(
+        constraint = user.load()?.authority == authority.key() @ ErrorCode::InvalidAuthority
+    )]
+    pub user: AccountLoader<'info, User>,
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"risk_ledger", user.key().as_ref()],
+        bump = risk_ledger.bump,
+    )]
+    pub risk_ledger: Account<'info, RiskLedger>,
+}
+
+#[derive(Debug, Clone, Copy, Default, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
+pub struct RiskLedgerParams {
+    /// length of a window in seconds
+    pub window_duration: i64,
+    /// max filled notional per window in quote precision, 0 for no limit
+    pub max_window_notional: u64,
+    /// max loss per window in quote precision, 0 for no limit
+    pub max_window_loss: u64,
+}
+
+#[cfg(test)]
+mod test {
+    use super::RiskLedgerParams;
+    use crate::error::ErrorCode;
+    use crate::state::RiskLedger;
+    use anchor_lang::prelude::Pubkey;
+    use drift::math::constants::{BASE_PRECISION, PRICE_PRECISION_I64, QUOTE_PRECISION_I64};
+
+    #[test]
+    pub fn calculate_notional_and_pnl() {
+        // maker buys 1 at 99 with oracle at 100
+        let (notional, pnl) = super::calculate_notional_and_pnl(
+            BASE_PRECISION as i128,
+            -99 * QUOTE_PRECISION_I64 as i128,
+            100 * PRICE_PRECISION_I64,
+            BASE_PRECISION,
+        )
+        .unwrap();
+        assert_eq!(notional, 100 * QUOTE_PRECISION_I64 as u64);
+        assert_eq!(pnl, QUOTE_PRECISION_I64);
+
+        // maker sells 0.5 spot at 99 with oracle at 100
+        let (notional, pnl) = super::calculate_notional_and_pnl(
+            -500_000,
+            99 * QUOTE_PRECISION_I64 as i128 / 2,
+            100 * PRICE_PRECISION_I64,
+            1_000_000,
+        )
+        .unwrap();
+        assert_eq!(notional, 50 * QUOTE_PRECISION_I64 as u64);
+        assert_eq!(pnl, -QUOTE_PRECISION_I64 / 2);
+    }
+
+    #[test]
+    pub fn set_params() {
+        let mut risk_ledger = RiskLedger {
+            user: Pubkey::default(),
+            window_duration: 0,
+            max_window_notional: 0,
+            max_window_loss: 0,
+            window_start_ts: 0,
+            window_notional: 0,
+            window_pnl: 0,
+            bump: 0,
+        };
+
+        let params = RiskLedgerParams {
+            window_duration: 0,
+            max_window_notional: 1_000,
+            max_window_loss: 100,
+        };
+        assert_eq!(
+            super::set_params(&mut risk_ledger, params),
+            Err(ErrorCode::InvalidWindowDuration.into())
+        );
+
+        let params = RiskLedgerParams {
+            window_duration: -1,
+            ..params
+        };
+        assert_eq!(
+            super::set_params(&mut risk_ledger, params),
+            Err(ErrorCode::InvalidWindowDuration.into())
+        );
+
+        let params = RiskLedgerParams {
+            window_duration: 60,
+            ..params
+        };
+        assert!(super::set_params(&mut risk_ledger, params).is_ok());
+        assert_eq!(risk_ledger.window_duration, 60);
+    }
+
+    #[test]
+    pub fn risk_ledger_limits() {
+        let mut risk_ledger = RiskLedger {
+            user: Pubkey::default(),
+            window_duration: 60,
+            max_window_notional: 1_000,
+            max_window_loss: 100,
+            window_start_ts: 0,
+            window_notional: 0,
+            window_pnl: 0,
+            bump: 0,
+        };
+
+        risk_ledger.record(1_000, -100).unwrap();
+        assert!(risk_ledger.check_limits().is_ok());
+
+        risk_ledger.record(1, 0).unwrap();
+        assert_eq!(
+            risk_ledger.check_limits(),
+            Err(ErrorCode::WindowNotionalLimitBreached.into())
+        );
+
+        // still in the window
+        risk_ledger.update_window(59).unwrap();
+        assert_eq!(risk_ledger.window_notional, 1_001);
+
+        risk_ledger.update_window(60).unwrap();
+        assert_eq!(risk_ledger.window_start_ts, 60);
+        assert_eq!(risk_ledger.window_notional, 0);
+
+        risk_ledger.record(0, -101).unwrap();
+        assert_eq!(
+            risk_ledger.check_limits(),
+            Err(ErrorCode::WindowLossLimitBreached.into())
+        );
+    }
+}
@@ -1,10 +1,12 @@
 use anchor_lang::prelude::*;
 
 pub mod error;
+pub mod events;
 pub mod instructions;
 pub mod state;
 
 use instructions::*;
+use state::MarketType;
 
 declare_id!("J1TnP8zvVxbtF5KFp5xRmWuvG9McnhzmBd9XGfCyuxFP");
 
@@ -26,6 +28,13 @@ pub mod jit_proxy {
         instructions::jit_signed_msg(ctx, params)
     }
 
+    pub fn jit_batch<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, JitBatch<'info>>,
+        params: JitBatchParams,
+    ) -> Result<()> {
+        instructions::jit_batch(ctx, params)
+    }
+
     pub fn check_order_constraints<'c: 'info, 'info>(
         ctx: Context<'_, '_, 'c, 'info, CheckOrderConstraints<'info>>,
         constraints: Vec<OrderConstraint>,
@@ -36,7 +45,74 @@ pub mod jit_proxy {
     pub fn arb_perp<'c: 'info, 'info>(
         ctx: Context<'_, '_, 'c, 'info, ArbPerp<'info>>,
         market_index: u16,
+        params: ArbPerpParams,
+    ) -> Result<()> {
+        instructions::arb_perp(ctx, market_index, params)
+    }
+
+    pub fn arb_spot<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ArbSpot<'info>>,
+        market_index: u16,
+        params: ArbSpotParams,
+    ) -> Result<()> {
+        instructions::arb_spot(ctx, market_index, params)
+    }
+
+    pub fn arb_basis<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ArbBasis<'info>>,
+        perp_market_index: u16,
+        spot_market_index: u16,
+        params: ArbBasisParams,
+    ) -> Result<()> {
+        instructions::arb_basis(ctx, perp_market_index, spot_market_index, params)
+    }
+
+    pub fn init_maker_config(
+        ctx: Context<InitMakerConfig>,
+        market_type: MarketType,
+        market_index: u16,
+        params: MakerConfigParams,
+    ) -> Result<()> {
+        instructions::init_maker_config(ctx, market_type, market_index, params)
+    }
+
+    pub fn update_maker_config(
+        ctx: Context<UpdateMakerConfig>,
+        params: MakerConfigParams,
+    ) -> Result<()> {
+        instructions::update_maker_config(ctx, params)
+    }
+
+    pub fn close_maker_config(ctx: Context<CloseMakerConfig>) -> Result<()> {
+        instructions::close_maker_config(ctx)
+    }
+
+    pub fn jit_with_config<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, JitWithConfig<'info>>,
+        taker_order_id: u32,
+    ) -> Result<()> {
+        instructions::jit_with_config(ctx, taker_order_id)
+    }
+
+    pub fn init_maker_control(ctx: Context<InitMakerControl>) -> Result<()> {
+        instructions::init_maker_control(ctx)
+    }
+
+    pub fn update_maker_control(
+        ctx: Context<UpdateMakerControl>,
+        params: MakerControlParams,
+    ) -> Result<()> {
+        instructions::update_maker_control(ctx, params)
+    }
+
+    pub fn init_risk_ledger(ctx: Context<InitRiskLedger>, params: RiskLedgerParams) -> Result<()> {
+        instructions::init_risk_ledger(ctx, params)
+    }
+
+    pub fn update_risk_ledger(
+        ctx: Context<UpdateRiskLedger>,
+        params: RiskLedgerParams,
     ) -> Result<()> {
-        instructions::arb_perp(ctx, market_index)
+        instructions::update_risk_ledger(ctx, params)
     }
 }
@@ -1,8 +1,14 @@
 use anchor_lang::prelude::*;
 use borsh::{BorshDeserialize, BorshSerialize};
+use drift::controller::position::PositionDirection as DriftPositionDirection;
+use drift::error::DriftResult;
+use drift::math::casting::Cast;
+use drift::math::safe_math::SafeMath;
 use drift::state::order_params::PostOnlyParam as DriftPostOnlyParam;
 use drift::state::user::MarketType as DriftMarketType;
 
+use crate::error::ErrorCode;
+
 #[derive(Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq, Debug, Eq)]
 pub enum PostOnlyParam {
     None,
@@ -26,6 +32,9 @@ impl PostOnlyParam {
 pub enum PriceType {
     Limit,
     Oracle,
+    OracleBps,  // bid/ask are signed basis points of the oracle price
+    Amm,        // bid/ask are offsets from the perp amm reserve price
+    OracleTwap, // bid/ask are offsets from the market's oracle twap
 }
 
 #[derive(Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq, Debug, Eq)]
@@ -42,3 +51,145 @@ impl MarketType {
         }
     }
 }
+
+impl From<DriftMarketType> for MarketType {
+    fn from(market_type: DriftMarketType) -> Self {
+        match market_type {
+            DriftMarketType::Spot => MarketType::Spot,
+            DriftMarketType::Perp => MarketType::Perp,
+        }
+    }
+}
+
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq, Debug, Eq)]
+pub enum PositionDirection {
+    Long,
+    Short,
+}
+
+impl From<DriftPositionDirection> for PositionDirection {
+    fn from(direction: DriftPositionDirection) -> Self {
+        match direction {
+            DriftPositionDirection::Long => PositionDirection::Long,
+            DriftPositionDirection::Short => PositionDirection::Short,
+        }
+    }
+}
+
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq, Debug, Eq, Default)]
+pub enum ArbSizing {
+    #[default]
+    QuoteAsset, // Size from quote asset token balance
+    FreeCollateral, // Size from initial margin free collateral
+}
+
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq, Debug, Eq, Default)]
+pub enum FillMode {
+    #[default]
+    Partial, // Fill as much of the taker order as the limits allow
+    FillOrKill, // Fail unless the whole taker order is filled
+}
+
+#[account]
+#[derive(Debug)]
+pub struct MakerConfig {
+    pub user: Pubkey,
+    pub market_type: MarketType,
+    pub market_index: u16,
+    pub max_position: i64,
+    pub min_position: i64,
+    pub bid: i64,
+    pub ask: i64,
+    pub price_type: PriceType,
+    pub post_only: Option<PostOnlyParam>,
+    pub bump: u8,
+}
+
+impl MakerConfig {
+    pub const SIZE: usize = 8 + 32 + 1 + 2 + 8 * 4 + 1 + 2 + 1;
+}
+
+#[account]
+#[derive(Debug)]
+pub struct MakerControl {
+    pub user: Pubkey,
+    pub paused: bool,
+    /// bit i pauses perp market i, markets from 128 are only paused by paused
+    pub paused_perp_markets: u128,
+    /// bit i pauses spot market i, markets from 128 are only paused by paused
+    pub paused_spot_markets: u128,
+    pub bump: u8,
+}
+
+impl MakerControl {
+    pub const SIZE: usize = 8 + 32 + 1 + 16 + 16 + 1;
+
+    pub fn is_paused(&self, market_type: MarketType, market_index: u16) -> bool {
+        let paused_markets = match market_type {
+            MarketType::Perp => self.paused_perp_markets,
+            MarketType::Spot => self.paused_spot_markets,
+        };
+
+        self.paused || (market_index < 128 && paused_markets & (1 << market_index) != 0)
+    }
+}
+
+#[account]
+#[derive(Debug)]
+pub struct RiskLedger {
+    pub user: Pubkey,
+    /// length of a window in seconds
+    pub window_duration: i64,
+    /// max filled notional per window in quote precision, 0 for no limit
+    pub max_window_notional: u64,
+    /// max loss per window in quote precision, 0 for no limit
+    pub max_window_loss: u64,
+    pub window_start_ts: i64,
+    pub window_notional: u64,
+    /// quote change plus base change marked at the oracle price
+    pub window_pnl: i64,
+    pub bump: u8,
+}
+
+impl RiskLedger {
+    pub const SIZE: usize = 8 + 32 + 8 * 6 + 1;
+
+    pub fn update_window(&mut self, now: i64) -> DriftResult<()> {
+        if now >= self.window_start_ts.safe_add(self.window_duration)? {
+            self.window_start_ts = now;
+            self.window_notional = 0;
+            self.window_pnl = 0;
+        }
+
+        Ok(())
+    }
+
+    pub fn record(&mut self, notional: u64, pnl: i64) -> DriftResult<()> {
+        self.window_notional = self.window_notional.safe_add(notional)?;
+        self.window_pnl = self.window_pnl.safe_add(pnl)?;
+
+        Ok(())
+    }
+
+    pub fn check_limits(&self) -> Result<()> {
+        if self.max_window_notional != 0 && self.window_notional > self.max_window_notional {
+            msg!(
+                "window notional {} > max window notional {}",
+                self.window_notional,
+                self.max_window_notional
+            );
+            return Err(ErrorCode::WindowNotionalLimitBreached.into());
+        }
+
+        if self.max_window_loss != 0 && self.window_pnl < -(self.max_window_loss.cast::<i64>()?) {
+            msg!(
+                "window pnl {} < -max window loss {}",
+                self.window_pnl,
+                self.max_window_loss
+            );
+            return Err(ErrorCode::WindowLossLimitBreached.into());
+        }
+
+        Ok(())
+    }
+}
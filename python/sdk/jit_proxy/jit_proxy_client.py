@@ -1,10 +1,11 @@
-from dataclasses import dataclass
+from dataclasses import dataclass, field
 from typing import Optional, cast
 
 from borsh_construct.enum import _rust_enum
 from sumtypes import constructor # type: ignore
 
 from solders.pubkey import Pubkey # type: ignore
+from solders.system_program import ID as SYS_PROGRAM_ID # type: ignore
 
 from anchorpy import Context, Program
 
@@ -16,6 +17,7 @@ from driftpy.types import (
     ReferrerInfo,
     MarketType,
     MakerInfo,
+    Order,
     is_variant,
 )
 from driftpy.drift_client import DriftClient
@@ -26,6 +28,28 @@ from driftpy.constants.numeric_constants import QUOTE_SPOT_MARKET_INDEX
 class PriceType:
     Limit = constructor()
     Oracle = constructor()
+    OracleBps = constructor()
+    Amm = constructor()
+    OracleTwap = constructor()
+
+
+@_rust_enum
+class FillMode:
+    Partial = constructor()
+    FillOrKill = constructor()
+
+
+@dataclass
+class PriceTier:
+    size_threshold: int
+    bid: int
+    ask: int
+
+
+@_rust_enum
+class ArbSizing:
+    QuoteAsset = constructor()
+    FreeCollateral = constructor()
 
 
 @dataclass
@@ -42,12 +66,119 @@ class JitIxParams:
     referrer_info: Optional[ReferrerInfo]
     sub_account_id: Optional[int]
     post_only: PostOnlyParams = PostOnlyParams.MustPostOnly()
+    # maker control pda, derived from the user when None. the derived account must exist, set
+    # use_maker_control to False to fill without one
+    maker_control: Optional[Pubkey] = None
+    use_maker_control: bool = True
+    max_fill_size: Optional[int] = None
+    min_fill_size: Optional[int] = None
+    price_ladder: list[PriceTier] = field(default_factory=list)
+    skew_per_base: Optional[int] = None
+    max_oracle_confidence_bps: Optional[int] = None
+    max_oracle_delay_slots: Optional[int] = None
+    valid_until_slot: Optional[int] = None
+    max_ts: Optional[int] = None
+    expected_oracle_price: Optional[int] = None
+    max_oracle_deviation_bps: Optional[int] = None
+    min_edge_bps: Optional[int] = None
+    fee_aware: bool = False
+    fill_mode: FillMode = FillMode.Partial()
+    reduce_only: bool = False
+
+
+@dataclass
+class JitBatchIxParams:
+    # the sub_account_id and maker control fields of the entries are ignored, the batch's are used
+    entries: list[JitIxParams]
+    best_effort: bool = False
+    sub_account_id: Optional[int] = None
+    # maker control pda, derived from the user when None. the derived account must exist, set
+    # use_maker_control to False to fill without one
+    maker_control: Optional[Pubkey] = None
+    use_maker_control: bool = True
 
 
 @dataclass
 class ArbIxParams:
     maker_infos: list[MakerInfo]
     market_index: int
+    min_profit_quote: int = 0
+    max_base_asset_amount: Optional[int] = None
+    sizing: ArbSizing = ArbSizing.QuoteAsset()
+    referrer_info: Optional[ReferrerInfo] = None
+    # maker control pda, derived from the user when None. the derived account must exist, set
+    # use_maker_control to False to fill without one
+    maker_control: Optional[Pubkey] = None
+    use_maker_control: bool = True
+
+
+@dataclass
+class ArbSpotIxParams:
+    maker_infos: list[MakerInfo]
+    market_index: int
+    base_tolerance: int = 0
+    referrer_info: Optional[ReferrerInfo] = None
+    # maker control pda, derived from the user when None. the derived account must exist, set
+    # use_maker_control to False to fill without one
+    maker_control: Optional[Pubkey] = None
+    use_maker_control: bool = True
+
+
+@dataclass
+class ArbBasisIxParams:
+    maker_infos: list[MakerInfo]
+    perp_market_index: int
+    spot_market_index: int
+    max_base_asset_amount: int
+    min_spread: int = 0
+    delta_tolerance: int = 0
+    min_profit_quote: int = 0
+    referrer_info: Optional[ReferrerInfo] = None
+    # maker control pda, derived from the user when None. the derived account must exist, set
+    # use_maker_control to False to fill without one
+    maker_control: Optional[Pubkey] = None
+    use_maker_control: bool = True
+
+
+@dataclass
+class MakerConfigIxParams:
+    max_position: int
+    min_position: int
+    bid: int
+    ask: int
+    price_type: PriceType = PriceType.Limit()
+    post_only: PostOnlyParams = PostOnlyParams.MustPostOnly()
+
+
+@dataclass
+class JitWithConfigIxParams:
+    taker_key: Pubkey
+    taker_stats_key: Pubkey
+    taker: UserAccount
+    taker_order_id: int
+    referrer_info: Optional[ReferrerInfo] = None
+    sub_account_id: Optional[int] = None
+    # maker control pda, derived from the user when None. the derived account must exist, set
+    # use_maker_control to False to fill without one
+    maker_control: Optional[Pubkey] = None
+    use_maker_control: bool = True
+
+
+@dataclass
+class MakerControlIxParams:
+    paused: bool
+    paused_perp_markets: list[int] = field(default_factory=list)
+    paused_spot_markets: list[int] = field(default_factory=list)
+
+
+@dataclass
+class RiskLedgerIxParams:
+    # length of a window in seconds
+    window_duration: int
+    # max filled notional per window in quote precision, 0 for no limit
+    max_window_notional: int = 0
+    # max loss per window in quote precision, 0 for no limit
+    max_window_loss: int = 0
 
 
 @dataclass
@@ -85,37 +216,386 @@ class JitProxyClient:
             ),
             None,
         )
+        remaining_accounts = self.get_jit_remaining_accounts(
+            params.taker, order, sub_account_id, params.referrer_info # type: ignore
+        )
+
+        jit_params = self.get_jit_params(params)
+
+        user = self.drift_client.get_user_account_public_key(sub_account_id)
+
+        ix = self.program.instruction["jit"]( # type: ignore
+            jit_params,
+            ctx=Context(
+                accounts={
+                    "state": self.drift_client.get_state_public_key(),
+                    "user": user,
+                    "user_stats": self.drift_client.get_user_stats_public_key(),
+                    "taker": params.taker_key,
+                    "taker_stats": params.taker_stats_key,
+                    "authority": self.drift_client.wallet.public_key,
+                    "drift_program": self.drift_client.program_id,
+                    "maker_control": self.get_maker_control(user, params),
+                    "risk_ledger": self.get_risk_ledger_public_key(user),
+                },
+                signers={self.drift_client.wallet}, # type: ignore
+                remaining_accounts=remaining_accounts,
+            ),
+        )
+
+        tx_sig_and_slot = await self.drift_client.send_ixs(ix)
+
+        return tx_sig_and_slot.tx_sig
+
+    async def jit_batch(self, params: JitBatchIxParams):
+        if self.program is None:
+            await self.init()
+
+        sub_account_id = self.drift_client.get_sub_account_id_for_ix(
+            params.sub_account_id # type: ignore
+        )
+
+        orders = [
+            next(
+                order
+                for order in entry.taker.orders
+                if order.order_id == entry.taker_order_id
+            )
+            for entry in params.entries
+        ]
+
+        writable_perp_market_indexes = set()
+        writable_spot_market_indexes = set()
+        for order in orders:
+            if is_variant(order.market_type, "Spot"):
+                writable_spot_market_indexes.add(order.market_index)
+                writable_spot_market_indexes.add(QUOTE_SPOT_MARKET_INDEX)
+            else:
+                writable_perp_market_indexes.add(order.market_index)
+
+        # market and oracle accounts for every entry, then each entry's extra accounts, then the
+        # taker and taker stats accounts
+        remaining_accounts = self.drift_client.get_remaining_accounts(
+            user_accounts=[entry.taker for entry in params.entries]
+            + [self.drift_client.get_user_account(sub_account_id)],
+            writable_perp_market_indexes=list(writable_perp_market_indexes),
+            writable_spot_market_indexes=list(writable_spot_market_indexes),
+        )
+
+        extra_accounts = []
+        for entry, order in zip(params.entries, orders):
+            accounts = self.get_jit_extra_accounts(order, entry.referrer_info)
+            extra_accounts.append((len(remaining_accounts), len(accounts)))
+            remaining_accounts.extend(accounts)
+
+        taker_indexes: dict[Pubkey, int] = {}
+        jit_batch_entries = []
+        for entry, (extra_accounts_index, extra_accounts_len) in zip(
+            params.entries, extra_accounts
+        ):
+            if entry.taker_key not in taker_indexes:
+                taker_indexes[entry.taker_key] = len(remaining_accounts)
+                remaining_accounts.append(
+                    AccountMeta(pubkey=entry.taker_key, is_writable=True, is_signer=False)
+                )
+                remaining_accounts.append(
+                    AccountMeta(
+                        pubkey=entry.taker_stats_key, is_writable=True, is_signer=False
+                    )
+                )
+
+            jit_batch_entries.append(
+                self.program.type["JitBatchEntry"]( # type: ignore
+                    taker_index=taker_indexes[entry.taker_key],
+                    params=self.get_jit_params(entry),
+                    extra_accounts_index=extra_accounts_index,
+                    extra_accounts_len=extra_accounts_len,
+                )
+            )
+
+        jit_batch_params = self.program.type["JitBatchParams"]( # type: ignore
+            entries=jit_batch_entries,
+            best_effort=params.best_effort,
+        )
+
+        user = self.drift_client.get_user_account_public_key(sub_account_id)
+
+        ix = self.program.instruction["jit_batch"]( # type: ignore
+            jit_batch_params,
+            ctx=Context(
+                accounts={
+                    "state": self.drift_client.get_state_public_key(),
+                    "user": user,
+                    "user_stats": self.drift_client.get_user_stats_public_key(),
+                    "authority": self.drift_client.wallet.public_key,
+                    "drift_program": self.drift_client.program_id,
+                    "maker_control": self.get_maker_control(user, params),
+                    "risk_ledger": self.get_risk_ledger_public_key(user),
+                },
+                signers={self.drift_client.wallet}, # type: ignore
+                remaining_accounts=remaining_accounts,
+            ),
+        )
+
+        tx_sig_and_slot = await self.drift_client.send_ixs(ix)
+
+        return tx_sig_and_slot.tx_sig
+
+    async def arb_perp(self, params: ArbIxParams):
+        if self.program is None:
+            await self.init()
+
+        remaining_accounts = self.get_arb_remaining_accounts(
+            params.maker_infos,
+            params.referrer_info,
+            writable_perp_market_indexes=[params.market_index],
+        )
+
+        arb_perp_params = self.program.type["ArbPerpParams"]( # type: ignore
+            min_profit_quote=params.min_profit_quote,
+            max_base_asset_amount=params.max_base_asset_amount,
+            sizing=self.get_arb_sizing(params.sizing),
+        )
+
+        user = self.drift_client.get_user_account_public_key()
+
+        ix = self.program.instruction["arb_perp"]( # type: ignore
+            params.market_index,
+            arb_perp_params,
+            ctx=Context(
+                accounts={
+                    "state": self.drift_client.get_state_public_key(),
+                    "user": user,
+                    "user_stats": self.drift_client.get_user_stats_public_key(),
+                    "authority": self.drift_client.wallet.public_key,
+                    "drift_program": self.drift_client.program_id,
+                    "maker_control": self.get_maker_control(user, params),
+                    "risk_ledger": self.get_risk_ledger_public_key(user),
+                },
+                signers={self.drift_client.wallet}, # type: ignore
+                remaining_accounts=remaining_accounts,
+            ),
+        )
+
+        tx_sig_and_slot = await self.drift_client.send_ixs(ix)
+
+        return tx_sig_and_slot.tx_sig
+
+    async def arb_spot(self, params: ArbSpotIxParams):
+        if self.program is None:
+            await self.init()
+
+        remaining_accounts = self.get_arb_remaining_accounts(
+            params.maker_infos,
+            params.referrer_info,
+            writable_spot_market_indexes=[params.market_index, QUOTE_SPOT_MARKET_INDEX],
+        )
+
+        arb_spot_params = self.program.type["ArbSpotParams"]( # type: ignore
+            base_tolerance=params.base_tolerance,
+        )
+
+        user = self.drift_client.get_user_account_public_key()
+
+        ix = self.program.instruction["arb_spot"]( # type: ignore
+            params.market_index,
+            arb_spot_params,
+            ctx=Context(
+                accounts={
+                    "state": self.drift_client.get_state_public_key(),
+                    "user": user,
+                    "user_stats": self.drift_client.get_user_stats_public_key(),
+                    "authority": self.drift_client.wallet.public_key,
+                    "drift_program": self.drift_client.program_id,
+                    "maker_control": self.get_maker_control(user, params),
+                    "risk_ledger": self.get_risk_ledger_public_key(user),
+                },
+                signers={self.drift_client.wallet}, # type: ignore
+                remaining_accounts=remaining_accounts,
+            ),
+        )
+
+        tx_sig_and_slot = await self.drift_client.send_ixs(ix)
+
+        return tx_sig_and_slot.tx_sig
+
+    async def arb_basis(self, params: ArbBasisIxParams):
+        if self.program is None:
+            await self.init()
+
+        remaining_accounts = self.get_arb_remaining_accounts(
+            params.maker_infos,
+            params.referrer_info,
+            writable_perp_market_indexes=[params.perp_market_index],
+            writable_spot_market_indexes=[
+                params.spot_market_index,
+                QUOTE_SPOT_MARKET_INDEX,
+            ],
+        )
+
+        arb_basis_params = self.program.type["ArbBasisParams"]( # type: ignore
+            min_spread=params.min_spread,
+            max_base_asset_amount=params.max_base_asset_amount,
+            delta_tolerance=params.delta_tolerance,
+            min_profit_quote=params.min_profit_quote,
+        )
+
+        user = self.drift_client.get_user_account_public_key()
+
+        ix = self.program.instruction["arb_basis"]( # type: ignore
+            params.perp_market_index,
+            params.spot_market_index,
+            arb_basis_params,
+            ctx=Context(
+                accounts={
+                    "state": self.drift_client.get_state_public_key(),
+                    "user": user,
+                    "user_stats": self.drift_client.get_user_stats_public_key(),
+                    "authority": self.drift_client.wallet.public_key,
+                    "drift_program": self.drift_client.program_id,
+                    "maker_control": self.get_maker_control(user, params),
+                    "risk_ledger": self.get_risk_ledger_public_key(user),
+                },
+                signers={self.drift_client.wallet}, # type: ignore
+                remaining_accounts=remaining_accounts,
+            ),
+        )
+
+        tx_sig_and_slot = await self.drift_client.send_ixs(ix)
+
+        return tx_sig_and_slot.tx_sig
+
+    def get_arb_remaining_accounts(
+        self,
+        maker_infos: list[MakerInfo],
+        referrer_info: Optional[ReferrerInfo],
+        writable_perp_market_indexes: list[int] = [],
+        writable_spot_market_indexes: list[int] = [],
+    ) -> list[AccountMeta]:
+        user_accounts = [self.drift_client.get_user_account()]
+        for maker_info in maker_infos:
+            user_accounts.append(maker_info.maker_user_account)
+
+        remaining_accounts = self.drift_client.get_remaining_accounts(
+            user_accounts=user_accounts,
+            writable_perp_market_indexes=writable_perp_market_indexes,
+            writable_spot_market_indexes=writable_spot_market_indexes,
+        )
+
+        for maker_info in maker_infos:
+            remaining_accounts.append(
+                AccountMeta(pubkey=maker_info.maker, is_writable=True, is_signer=False)
+            )
+            remaining_accounts.append(
+                AccountMeta(
+                    pubkey=maker_info.maker_stats, is_writable=True, is_signer=False
+                )
+            )
+
+        if referrer_info is not None:
+            referrer_is_maker = any(
+                maker_info.maker == referrer_info.referrer
+                for maker_info in maker_infos
+            )
+            if not referrer_is_maker:
+                remaining_accounts.append(
+                    AccountMeta(
+                        pubkey=referrer_info.referrer,
+                        is_writable=True,
+                        is_signer=False,
+                    )
+                )
+                remaining_accounts.append(
+                    AccountMeta(
+                        pubkey=referrer_info.referrer_stats,
+                        is_writable=True,
+                        is_signer=False,
+                    )
+                )
+
+        return remaining_accounts
+
+    def get_jit_params(self, params: JitIxParams):
+        return self.program.type["JitParams"]( # type: ignore
+            taker_order_id=params.taker_order_id,
+            max_position=cast(int, params.max_position),
+            min_position=cast(int, params.min_position),
+            bid=cast(int, params.bid),
+            ask=cast(int, params.ask),
+            price_type=self.get_price_type(params.price_type), # type: ignore
+            post_only=self.get_post_only(params.post_only),
+            max_fill_size=params.max_fill_size,
+            min_fill_size=params.min_fill_size,
+            price_ladder=[
+                self.program.type["PriceTier"]( # type: ignore
+                    size_threshold=tier.size_threshold,
+                    bid=tier.bid,
+                    ask=tier.ask,
+                )
+                for tier in params.price_ladder
+            ],
+            skew_per_base=params.skew_per_base,
+            max_oracle_confidence_bps=params.max_oracle_confidence_bps,
+            max_oracle_delay_slots=params.max_oracle_delay_slots,
+            valid_until_slot=params.valid_until_slot,
+            max_ts=params.max_ts,
+            expected_oracle_price=params.expected_oracle_price,
+            max_oracle_deviation_bps=params.max_oracle_deviation_bps,
+            min_edge_bps=params.min_edge_bps,
+            fee_aware=params.fee_aware,
+            fill_mode=self.get_fill_mode(params.fill_mode),
+            reduce_only=params.reduce_only,
+        )
+
+    def get_jit_remaining_accounts(
+        self,
+        taker: UserAccount,
+        order: Order,
+        sub_account_id: int,
+        referrer_info: Optional[ReferrerInfo],
+    ) -> list[AccountMeta]:
         remaining_accounts = self.drift_client.get_remaining_accounts(
             user_accounts=[
-                params.taker,
+                taker,
                 self.drift_client.get_user_account(sub_account_id),
             ],
-            writable_spot_market_indexes=[order.market_index, QUOTE_SPOT_MARKET_INDEX] # type: ignore
-            if is_variant(order.market_type, "Spot") # type: ignore
+            writable_spot_market_indexes=[order.market_index, QUOTE_SPOT_MARKET_INDEX]
+            if is_variant(order.market_type, "Spot")
             else [],
-            writable_perp_market_indexes=[order.market_index] # type: ignore
-            if is_variant(order.market_type, "Perp") # type: ignore
+            writable_perp_market_indexes=[order.market_index]
+            if is_variant(order.market_type, "Perp")
             else [],
         )
 
-        if params.referrer_info is not None:
-            remaining_accounts.append(
+        remaining_accounts.extend(self.get_jit_extra_accounts(order, referrer_info))
+
+        return remaining_accounts
+
+    def get_jit_extra_accounts(
+        self,
+        order: Order,
+        referrer_info: Optional[ReferrerInfo],
+    ) -> list[AccountMeta]:
+        accounts: list[AccountMeta] = []
+
+        if referrer_info is not None:
+            accounts.append(
                 AccountMeta(
-                    pubkey=params.referrer_info.referrer,
+                    pubkey=referrer_info.referrer,
                     is_writable=True,
                     is_signer=False,
                 )
             )
-            remaining_accounts.append(
+            accounts.append(
                 AccountMeta(
-                    pubkey=params.referrer_info.referrer_stats,
+                    pubkey=referrer_info.referrer_stats,
                     is_writable=True,
                     is_signer=False,
                 )
             )
 
         if is_variant(order.market_type, "Spot"): # type: ignore
-            remaining_accounts.append(
+            accounts.append(
                 AccountMeta(
                     pubkey=self.drift_client.get_spot_market_account( # type: ignore
                         order.market_index # type: ignore
@@ -124,7 +604,7 @@ class JitProxyClient:
                     is_signer=False,
                 )
             )
-            remaining_accounts.append(
+            accounts.append(
                 AccountMeta(
                     pubkey=self.drift_client.get_quote_spot_market_account().vault, # type: ignore
                     is_writable=False,
@@ -132,29 +612,136 @@ class JitProxyClient:
                 )
             )
 
-        jit_params = self.program.type["JitParams"]( # type: ignore
-            taker_order_id=params.taker_order_id,
-            max_position=cast(int, params.max_position),
-            min_position=cast(int, params.min_position),
-            bid=cast(int, params.bid),
-            ask=cast(int, params.ask),
-            price_type=self.get_price_type(params.price_type), # type: ignore
-            post_only=self.get_post_only(params.post_only),
+        return accounts
+
+    async def init_maker_config(
+        self,
+        market_type: MarketType,
+        market_index: int,
+        params: MakerConfigIxParams,
+        sub_account_id: Optional[int] = None,
+    ):
+        if self.program is None:
+            await self.init()
+
+        user = self.drift_client.get_user_account_public_key(sub_account_id)
+
+        ix = self.program.instruction["init_maker_config"]( # type: ignore
+            self.get_market_type(market_type),
+            market_index,
+            self.get_maker_config_params(params),
+            ctx=Context(
+                accounts={
+                    "user": user,
+                    "authority": self.drift_client.wallet.public_key,
+                    "maker_config": self.get_maker_config_public_key(
+                        user, market_type, market_index
+                    ),
+                    "system_program": SYS_PROGRAM_ID,
+                },
+                signers={self.drift_client.wallet}, # type: ignore
+            ),
         )
 
-        ix = self.program.instruction["jit"]( # type: ignore
-            jit_params,
+        tx_sig_and_slot = await self.drift_client.send_ixs(ix)
+
+        return tx_sig_and_slot.tx_sig
+
+    async def update_maker_config(
+        self,
+        market_type: MarketType,
+        market_index: int,
+        params: MakerConfigIxParams,
+        sub_account_id: Optional[int] = None,
+    ):
+        if self.program is None:
+            await self.init()
+
+        user = self.drift_client.get_user_account_public_key(sub_account_id)
+
+        ix = self.program.instruction["update_maker_config"]( # type: ignore
+            self.get_maker_config_params(params),
             ctx=Context(
                 accounts={
-                    "state": self.drift_client.get_state_public_key(),
-                    "user": self.drift_client.get_user_account_public_key(
-                        sub_account_id
+                    "user": user,
+                    "authority": self.drift_client.wallet.public_key,
+                    "maker_config": self.get_maker_config_public_key(
+                        user, market_type, market_index
                     ),
-                    "user_stats": self.drift_client.get_user_stats_public_key(),
-                    "taker": params.taker_key,
-                    "taker_stats": params.taker_stats_key,
+                },
+                signers={self.drift_client.wallet}, # type: ignore
+            ),
+        )
+
+        tx_sig_and_slot = await self.drift_client.send_ixs(ix)
+
+        return tx_sig_and_slot.tx_sig
+
+    async def close_maker_config(
+        self,
+        market_type: MarketType,
+        market_index: int,
+        sub_account_id: Optional[int] = None,
+    ):
+        if self.program is None:
+            await self.init()
+
+        user = self.drift_client.get_user_account_public_key(sub_account_id)
+
+        ix = self.program.instruction["close_maker_config"]( # type: ignore
+            ctx=Context(
+                accounts={
+                    "user": user,
                     "authority": self.drift_client.wallet.public_key,
-                    "drift_program": self.drift_client.program_id,
+                    "maker_config": self.get_maker_config_public_key(
+                        user, market_type, market_index
+                    ),
+                },
+                signers={self.drift_client.wallet}, # type: ignore
+            ),
+        )
+
+        tx_sig_and_slot = await self.drift_client.send_ixs(ix)
+
+        return tx_sig_and_slot.tx_sig
+
+    async def jit_with_config(self, params: JitWithConfigIxParams):
+        if self.program is None:
+            await self.init()
+
+        sub_account_id = self.drift_client.get_sub_account_id_for_ix(
+            params.sub_account_id # type: ignore
+        )
+
+        order = next(
+            order
+            for order in params.taker.orders
+            if order.order_id == params.taker_order_id
+        )
+        remaining_accounts = self.get_jit_remaining_accounts(
+            params.taker, order, sub_account_id, params.referrer_info
+        )
+
+        user = self.drift_client.get_user_account_public_key(sub_account_id)
+
+        ix = self.program.instruction["jit_with_config"]( # type: ignore
+            params.taker_order_id,
+            ctx=Context(
+                accounts={
+                    "jit": {
+                        "state": self.drift_client.get_state_public_key(),
+                        "user": user,
+                        "user_stats": self.drift_client.get_user_stats_public_key(),
+                        "taker": params.taker_key,
+                        "taker_stats": params.taker_stats_key,
+                        "authority": self.drift_client.wallet.public_key,
+                        "drift_program": self.drift_client.program_id,
+                        "maker_control": self.get_maker_control(user, params),
+                        "risk_ledger": self.get_risk_ledger_public_key(user),
+                    },
+                    "maker_config": self.get_maker_config_public_key(
+                        user, order.market_type, order.market_index
+                    ),
                 },
                 signers={self.drift_client.wallet}, # type: ignore
                 remaining_accounts=remaining_accounts,
@@ -165,13 +752,191 @@ class JitProxyClient:
 
         return tx_sig_and_slot.tx_sig
 
+    def get_maker_config_params(self, params: MakerConfigIxParams):
+        return self.program.type["MakerConfigParams"]( # type: ignore
+            max_position=params.max_position,
+            min_position=params.min_position,
+            bid=params.bid,
+            ask=params.ask,
+            price_type=self.get_price_type(params.price_type),
+            post_only=self.get_post_only(params.post_only),
+        )
+
+    def get_maker_config_public_key(
+        self, user: Pubkey, market_type: MarketType, market_index: int
+    ) -> Pubkey:
+        return Pubkey.find_program_address(
+            [
+                b"maker_config",
+                bytes(user),
+                bytes([0 if is_variant(market_type, "Perp") else 1]),
+                market_index.to_bytes(2, "little"),
+            ],
+            self.program_id,
+        )[0]
+
+    def get_market_type(self, market_type: MarketType):
+        if is_variant(market_type, "Perp"):
+            return self.program.type["MarketType"].Perp() # type: ignore
+        elif is_variant(market_type, "Spot"):
+            return self.program.type["MarketType"].Spot() # type: ignore
+        else:
+            raise ValueError(f"Unknown market type: {str(market_type)}")
+
+    async def init_maker_control(self, sub_account_id: Optional[int] = None):
+        if self.program is None:
+            await self.init()
+
+        user = self.drift_client.get_user_account_public_key(sub_account_id)
+
+        ix = self.program.instruction["init_maker_control"]( # type: ignore
+            ctx=Context(
+                accounts={
+                    "user": user,
+                    "authority": self.drift_client.wallet.public_key,
+                    "maker_control": self.get_maker_control_public_key(user),
+                    "system_program": SYS_PROGRAM_ID,
+                },
+                signers={self.drift_client.wallet}, # type: ignore
+            ),
+        )
+
+        tx_sig_and_slot = await self.drift_client.send_ixs(ix)
+
+        return tx_sig_and_slot.tx_sig
+
+    async def update_maker_control(
+        self, params: MakerControlIxParams, sub_account_id: Optional[int] = None
+    ):
+        if self.program is None:
+            await self.init()
+
+        user = self.drift_client.get_user_account_public_key(sub_account_id)
+
+        maker_control_params = self.program.type["MakerControlParams"]( # type: ignore
+            paused=params.paused,
+            paused_perp_markets=params.paused_perp_markets,
+            paused_spot_markets=params.paused_spot_markets,
+        )
+
+        ix = self.program.instruction["update_maker_control"]( # type: ignore
+            maker_control_params,
+            ctx=Context(
+                accounts={
+                    "user": user,
+                    "authority": self.drift_client.wallet.public_key,
+                    "maker_control": self.get_maker_control_public_key(user),
+                },
+                signers={self.drift_client.wallet}, # type: ignore
+            ),
+        )
+
+        tx_sig_and_slot = await self.drift_client.send_ixs(ix)
+
+        return tx_sig_and_slot.tx_sig
+
+    async def init_risk_ledger(
+        self, params: RiskLedgerIxParams, sub_account_id: Optional[int] = None
+    ):
+        if self.program is None:
+            await self.init()
+
+        user = self.drift_client.get_user_account_public_key(sub_account_id)
+
+        ix = self.program.instruction["init_risk_ledger"]( # type: ignore
+            self.get_risk_ledger_params(params),
+            ctx=Context(
+                accounts={
+                    "user": user,
+                    "authority": self.drift_client.wallet.public_key,
+                    "risk_ledger": self.get_risk_ledger_public_key(user),
+                    "system_program": SYS_PROGRAM_ID,
+                },
+                signers={self.drift_client.wallet}, # type: ignore
+            ),
+        )
+
+        tx_sig_and_slot = await self.drift_client.send_ixs(ix)
+
+        return tx_sig_and_slot.tx_sig
+
+    async def update_risk_ledger(
+        self, params: RiskLedgerIxParams, sub_account_id: Optional[int] = None
+    ):
+        if self.program is None:
+            await self.init()
+
+        user = self.drift_client.get_user_account_public_key(sub_account_id)
+
+        ix = self.program.instruction["update_risk_ledger"]( # type: ignore
+            self.get_risk_ledger_params(params),
+            ctx=Context(
+                accounts={
+                    "user": user,
+                    "authority": self.drift_client.wallet.public_key,
+                    "risk_ledger": self.get_risk_ledger_public_key(user),
+                },
+                signers={self.drift_client.wallet}, # type: ignore
+            ),
+        )
+
+        tx_sig_and_slot = await self.drift_client.send_ixs(ix)
+
+        return tx_sig_and_slot.tx_sig
+
+    def get_risk_ledger_params(self, params: RiskLedgerIxParams):
+        return self.program.type["RiskLedgerParams"]( # type: ignore
+            window_duration=params.window_duration,
+            max_window_notional=params.max_window_notional,
+            max_window_loss=params.max_window_loss,
+        )
+
+    def get_maker_control(self, user: Pubkey, params) -> Optional[Pubkey]:
+        if not params.use_maker_control:
+            return None
+        if params.maker_control is not None:
+            return params.maker_control
+        return self.get_maker_control_public_key(user)
+
+    def get_maker_control_public_key(self, user: Pubkey) -> Pubkey:
+        return Pubkey.find_program_address(
+            [b"maker_control", bytes(user)], self.program_id
+        )[0]
+
+    def get_risk_ledger_public_key(self, user: Pubkey) -> Pubkey:
+        return Pubkey.find_program_address(
+            [b"risk_ledger", bytes(user)], self.program_id
+        )[0]
+
+    def get_arb_sizing(self, sizing: ArbSizing):
+        if is_variant(sizing, "FreeCollateral"):
+            return self.program.type["ArbSizing"].FreeCollateral() # type: ignore
+        elif is_variant(sizing, "QuoteAsset"):
+            return self.program.type["ArbSizing"].QuoteAsset() # type: ignore
+        else:
+            raise ValueError(f"Unknown arb sizing: {str(sizing)}")
+
     def get_price_type(self, price_type: PriceType):
         if is_variant(price_type, "Oracle"):
             return self.program.type["PriceType"].Oracle() # type: ignore
         elif is_variant(price_type, "Limit"):
             return self.program.type["PriceType"].Limit() # type: ignore
+        elif is_variant(price_type, "OracleBps"):
+            return self.program.type["PriceType"].OracleBps() # type: ignore
+        elif is_variant(price_type, "Amm"):
+            return self.program.type["PriceType"].Amm() # type: ignore
+        elif is_variant(price_type, "OracleTwap"):
+            return self.program.type["PriceType"].OracleTwap() # type: ignore
         else: 
             raise ValueError(f"Unknown price type: {str(price_type)}")
+
+    def get_fill_mode(self, fill_mode: FillMode):
+        if is_variant(fill_mode, "Partial"):
+            return self.program.type["FillMode"].Partial() # type: ignore
+        elif is_variant(fill_mode, "FillOrKill"):
+            return self.program.type["FillMode"].FillOrKill() # type: ignore
+        else:
+            raise ValueError(f"Unknown fill mode: {str(fill_mode)}")
         
     def get_post_only(self, post_only: PostOnlyParams):
         if is_variant(post_only, "MustPostOnly"):
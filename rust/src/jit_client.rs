@@ -0,0 +1,495 @@
+//! Builds jit-proxy ixs with the program's current param layout and accounts
+//!
+//! drift-rs' jit_client serializes the 0.21.0 JitParams and doesn't pass the maker_control and
+//! risk_ledger accounts, so the ixs are built here instead.
+use std::{borrow::Cow, collections::BTreeMap};
+
+use borsh::BorshSerialize;
+use drift_rs::{
+    constants::{state_account, PROGRAM_ID as DRIFT_PROGRAM_ID},
+    swift_order_subscriber::SignedOrderInfo,
+    types::{accounts::User, MarketType, ReferrerInfo, RpcSendTransactionConfig},
+    DriftClient, Pubkey, TransactionBuilder, Wallet,
+};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    message::VersionedMessage,
+    pubkey,
+    signature::Signature,
+};
+
+use crate::types::{ComputeBudgetParams, JitError, JitResult};
+
+pub const JIT_PROXY_PROGRAM_ID: Pubkey = pubkey!("J1TnP8zvVxbtF5KFp5xRmWuvG9McnhzmBd9XGfCyuxFP");
+
+const QUOTE_SPOT_MARKET_INDEX: u16 = 0;
+
+/// sha256("global:jit")[..8]
+const JIT_DISCRIMINATOR: [u8; 8] = [99, 42, 97, 140, 152, 62, 167, 234];
+/// sha256("global:jit_signed_msg")[..8]
+const JIT_SIGNED_MSG_DISCRIMINATOR: [u8; 8] = [134, 130, 156, 72, 37, 120, 153, 21];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, BorshSerialize)]
+pub enum PostOnlyParam {
+    None,
+    MustPostOnly,
+    TryPostOnly,
+    Slide,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, BorshSerialize)]
+pub enum PriceType {
+    Limit,
+    Oracle,
+    OracleBps,
+    Amm,
+    OracleTwap,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, BorshSerialize)]
+pub enum FillMode {
+    Partial,
+    FillOrKill,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, BorshSerialize)]
+pub struct PriceTier {
+    pub size_threshold: u64,
+    pub bid: i64,
+    pub ask: i64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, BorshSerialize)]
+struct JitParams {
+    taker_order_id: u32,
+    max_position: i64,
+    min_position: i64,
+    bid: i64,
+    ask: i64,
+    price_type: PriceType,
+    post_only: Option<PostOnlyParam>,
+    max_fill_size: Option<u64>,
+    min_fill_size: Option<u64>,
+    price_ladder: Vec<PriceTier>,
+    skew_per_base: Option<i64>,
+    max_oracle_confidence_bps: Option<u64>,
+    max_oracle_delay_slots: Option<u64>,
+    valid_until_slot: Option<u64>,
+    max_ts: Option<i64>,
+    expected_oracle_price: Option<i64>,
+    max_oracle_deviation_bps: Option<u64>,
+    min_edge_bps: Option<u64>,
+    fee_aware: bool,
+    fill_mode: FillMode,
+    reduce_only: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, BorshSerialize)]
+struct JitSignedMsgParams {
+    signed_msg_order_uuid: [u8; 8],
+    max_position: i64,
+    min_position: i64,
+    bid: i64,
+    ask: i64,
+    price_type: PriceType,
+    post_only: Option<PostOnlyParam>,
+    max_oracle_confidence_bps: Option<u64>,
+    max_oracle_delay_slots: Option<u64>,
+    valid_until_slot: Option<u64>,
+    max_ts: Option<i64>,
+    expected_oracle_price: Option<i64>,
+    max_oracle_deviation_bps: Option<u64>,
+    reduce_only: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct JitIxParams {
+    pub max_position: i64,
+    pub min_position: i64,
+    pub bid: i64,
+    pub ask: i64,
+    pub price_type: PriceType,
+    pub post_only: Option<PostOnlyParam>,
+}
+
+impl JitIxParams {
+    pub fn new(
+        max_position: i64,
+        min_position: i64,
+        bid: i64,
+        ask: i64,
+        price_type: PriceType,
+        post_only: Option<PostOnlyParam>,
+    ) -> Self {
+        Self {
+            max_position,
+            min_position,
+            bid,
+            ask,
+            price_type,
+            post_only,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct JitTakerParams {
+    pub taker_key: Pubkey,
+    pub taker_stats_key: Pubkey,
+    pub taker: User,
+    pub taker_referrer_info: Option<ReferrerInfo>,
+}
+
+impl JitTakerParams {
+    pub fn new(
+        taker_key: Pubkey,
+        taker_stats_key: Pubkey,
+        taker: User,
+        taker_referrer_info: Option<ReferrerInfo>,
+    ) -> Self {
+        Self {
+            taker_key,
+            taker_stats_key,
+            taker,
+            taker_referrer_info,
+        }
+    }
+}
+
+pub fn derive_maker_control(user: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"maker_control", user.as_ref()], &JIT_PROXY_PROGRAM_ID).0
+}
+
+pub fn derive_risk_ledger(user: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"risk_ledger", user.as_ref()], &JIT_PROXY_PROGRAM_ID).0
+}
+
+fn derive_signed_msg_user_orders(authority: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"SIGNED_MSG", authority.as_ref()], &DRIFT_PROGRAM_ID).0
+}
+
+#[derive(Clone)]
+pub struct JitProxyClient {
+    drift_client: DriftClient,
+    config: RpcSendTransactionConfig,
+    cu_params: Option<ComputeBudgetParams>,
+    use_maker_control: bool,
+}
+
+impl JitProxyClient {
+    /// The maker's maker control pda is passed by default and must exist, see
+    /// [`JitProxyClient::with_maker_control`]. The risk ledger pda is always required
+    pub fn new(
+        drift_client: DriftClient,
+        config: Option<RpcSendTransactionConfig>,
+        cu_params: Option<ComputeBudgetParams>,
+    ) -> Self {
+        Self {
+            drift_client,
+            config: config.unwrap_or_default(),
+            cu_params,
+            use_maker_control: true,
+        }
+    }
+
+    /// Set to false to fill without a maker control account
+    pub fn with_maker_control(mut self, use_maker_control: bool) -> Self {
+        self.use_maker_control = use_maker_control;
+        self
+    }
+
+    pub async fn jit(
+        &self,
+        taker_order_id: u32,
+        taker_params: &JitTakerParams,
+        jit_ix_params: JitIxParams,
+        authority: &Pubkey,
+        sub_account_id: Option<u16>,
+    ) -> JitResult<Signature> {
+        let sub_account = Wallet::derive_user_account(authority, sub_account_id.unwrap_or(0));
+        let maker = self.drift_client.get_user_account(&sub_account).await?;
+
+        let order = taker_params
+            .taker
+            .orders
+            .iter()
+            .find(|order| order.order_id == taker_order_id)
+            .copied()
+            .ok_or_else(|| JitError::Sdk(format!("taker order {taker_order_id} not found")))?;
+
+        let params = JitParams {
+            taker_order_id,
+            max_position: jit_ix_params.max_position,
+            min_position: jit_ix_params.min_position,
+            bid: jit_ix_params.bid,
+            ask: jit_ix_params.ask,
+            price_type: jit_ix_params.price_type,
+            post_only: jit_ix_params.post_only,
+            max_fill_size: None,
+            min_fill_size: None,
+            price_ladder: vec![],
+            skew_per_base: None,
+            max_oracle_confidence_bps: None,
+            max_oracle_delay_slots: None,
+            valid_until_slot: None,
+            max_ts: None,
+            expected_oracle_price: None,
+            max_oracle_deviation_bps: None,
+            min_edge_bps: None,
+            fee_aware: false,
+            fill_mode: FillMode::Partial,
+            reduce_only: false,
+        };
+
+        let mut accounts = vec![
+            AccountMeta::new_readonly(*state_account(), false),
+            AccountMeta::new(sub_account, false),
+            AccountMeta::new(Wallet::derive_stats_account(authority), false),
+            AccountMeta::new(taker_params.taker_key, false),
+            AccountMeta::new(taker_params.taker_stats_key, false),
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new_readonly(DRIFT_PROGRAM_ID, false),
+        ];
+        accounts.extend(self.maker_and_risk_accounts(&sub_account));
+        accounts.extend(self.remaining_accounts(
+            &[&taker_params.taker, &maker],
+            order.market_type,
+            order.market_index,
+            taker_params.taker_referrer_info.as_ref(),
+        )?);
+
+        let mut data = JIT_DISCRIMINATOR.to_vec();
+        params
+            .serialize(&mut data)
+            .map_err(|err| JitError::Sdk(err.to_string()))?;
+
+        let ix = Instruction {
+            program_id: JIT_PROXY_PROGRAM_ID,
+            accounts,
+            data,
+        };
+
+        let tx = self.build_tx(sub_account, &maker, None, ix);
+
+        Ok(self
+            .drift_client
+            .sign_and_send_with_config(tx, None, self.config)
+            .await?)
+    }
+
+    /// Places the signed msg taker order and fills it in the same tx
+    pub async fn try_swift_fill(
+        &self,
+        signed_order_info: &SignedOrderInfo,
+        taker_params: &JitTakerParams,
+        jit_ix_params: &JitIxParams,
+        authority: &Pubkey,
+        sub_account_id: Option<u16>,
+    ) -> JitResult<Signature> {
+        let sub_account = Wallet::derive_user_account(authority, sub_account_id.unwrap_or(0));
+        let maker = self.drift_client.get_user_account(&sub_account).await?;
+
+        let order_params = signed_order_info.order_params();
+        let signed_msg_order_uuid: [u8; 8] = signed_order_info
+            .order_uuid_str()
+            .as_bytes()
+            .try_into()
+            .map_err(|_| JitError::Sdk("invalid signed msg order uuid".to_string()))?;
+
+        let params = JitSignedMsgParams {
+            signed_msg_order_uuid,
+            max_position: jit_ix_params.max_position,
+            min_position: jit_ix_params.min_position,
+            bid: jit_ix_params.bid,
+            ask: jit_ix_params.ask,
+            price_type: jit_ix_params.price_type,
+            post_only: jit_ix_params.post_only,
+            max_oracle_confidence_bps: None,
+            max_oracle_delay_slots: None,
+            valid_until_slot: None,
+            max_ts: None,
+            expected_oracle_price: None,
+            max_oracle_deviation_bps: None,
+            reduce_only: false,
+        };
+
+        let mut accounts = vec![
+            AccountMeta::new_readonly(*state_account(), false),
+            AccountMeta::new(sub_account, false),
+            AccountMeta::new(Wallet::derive_stats_account(authority), false),
+            AccountMeta::new(taker_params.taker_key, false),
+            AccountMeta::new(taker_params.taker_stats_key, false),
+            AccountMeta::new(
+                derive_signed_msg_user_orders(&taker_params.taker.authority),
+                false,
+            ),
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new_readonly(DRIFT_PROGRAM_ID, false),
+        ];
+        accounts.extend(self.maker_and_risk_accounts(&sub_account));
+        accounts.extend(self.remaining_accounts(
+            &[&taker_params.taker, &maker],
+            order_params.market_type,
+            order_params.market_index,
+            taker_params.taker_referrer_info.as_ref(),
+        )?);
+
+        let mut data = JIT_SIGNED_MSG_DISCRIMINATOR.to_vec();
+        params
+            .serialize(&mut data)
+            .map_err(|err| JitError::Sdk(err.to_string()))?;
+
+        let ix = Instruction {
+            program_id: JIT_PROXY_PROGRAM_ID,
+            accounts,
+            data,
+        };
+
+        let tx = self.build_tx(
+            sub_account,
+            &maker,
+            Some((signed_order_info, &taker_params.taker)),
+            ix,
+        );
+
+        Ok(self
+            .drift_client
+            .sign_and_send_with_config(tx, None, self.config)
+            .await?)
+    }
+
+    /// Builds the tx with the signed msg taker order placed before the ix if given
+    fn build_tx(
+        &self,
+        sub_account: Pubkey,
+        maker: &User,
+        signed_order: Option<(&SignedOrderInfo, &User)>,
+        ix: Instruction,
+    ) -> VersionedMessage {
+        let mut tx = TransactionBuilder::new(
+            self.drift_client.program_data(),
+            sub_account,
+            Cow::Borrowed(maker),
+            false,
+        );
+        if let Some(cu_params) = self.cu_params {
+            tx = tx.with_priority_fee(cu_params.microlamports_per_cu(), Some(cu_params.cu_limit()));
+        }
+        if let Some((signed_order_info, taker)) = signed_order {
+            tx = tx.place_swift_order(signed_order_info, taker);
+        }
+        tx.add_ix(ix).build()
+    }
+
+    /// Returns the maker_control and risk_ledger accounts, anchor reads the program id as a
+    /// missing optional account
+    fn maker_and_risk_accounts(&self, sub_account: &Pubkey) -> [AccountMeta; 2] {
+        let maker_control = if self.use_maker_control {
+            derive_maker_control(sub_account)
+        } else {
+            JIT_PROXY_PROGRAM_ID
+        };
+
+        [
+            AccountMeta::new_readonly(maker_control, false),
+            AccountMeta::new(derive_risk_ledger(sub_account), false),
+        ]
+    }
+
+    /// Returns the oracle, spot market and perp market accounts of the users' positions and the
+    /// filled market, followed by the referrer and spot vault accounts
+    fn remaining_accounts(
+        &self,
+        users: &[&User],
+        market_type: MarketType,
+        market_index: u16,
+        referrer_info: Option<&ReferrerInfo>,
+    ) -> JitResult<Vec<AccountMeta>> {
+        let is_spot = matches!(market_type, MarketType::Spot);
+        let program_data = self.drift_client.program_data();
+        let mut oracles = BTreeMap::<Pubkey, AccountMeta>::new();
+        let mut spot_markets = BTreeMap::<u16, AccountMeta>::new();
+        let mut perp_markets = BTreeMap::<u16, AccountMeta>::new();
+
+        let mut add_spot_market = |market_index: u16, is_writable: bool| -> JitResult<()> {
+            let spot_market = program_data
+                .spot_market_config_by_index(market_index)
+                .ok_or_else(|| JitError::Sdk(format!("spot market {market_index} not found")))?;
+            oracles.insert(
+                spot_market.oracle,
+                AccountMeta::new_readonly(spot_market.oracle, false),
+            );
+            let account = spot_markets
+                .entry(market_index)
+                .or_insert_with(|| AccountMeta::new_readonly(spot_market.pubkey, false));
+            account.is_writable |= is_writable;
+            Ok(())
+        };
+
+        add_spot_market(QUOTE_SPOT_MARKET_INDEX, is_spot)?;
+        if is_spot {
+            add_spot_market(market_index, true)?;
+        }
+        for user in users {
+            for position in user.spot_positions.iter() {
+                if position.scaled_balance != 0 || position.open_orders != 0 {
+                    add_spot_market(position.market_index, false)?;
+                }
+            }
+        }
+
+        let mut add_perp_market = |market_index: u16, is_writable: bool| -> JitResult<()> {
+            let perp_market = program_data
+                .perp_market_config_by_index(market_index)
+                .ok_or_else(|| JitError::Sdk(format!("perp market {market_index} not found")))?;
+            oracles.insert(
+                perp_market.amm.oracle,
+                AccountMeta::new_readonly(perp_market.amm.oracle, false),
+            );
+            let account = perp_markets
+                .entry(market_index)
+                .or_insert_with(|| AccountMeta::new_readonly(perp_market.pubkey, false));
+            account.is_writable |= is_writable;
+            Ok(())
+        };
+
+        if !is_spot {
+            add_perp_market(market_index, true)?;
+        }
+        for user in users {
+            for position in user.perp_positions.iter() {
+                if position.base_asset_amount != 0
+                    || position.quote_asset_amount != 0
+                    || position.open_orders != 0
+                {
+                    add_perp_market(position.market_index, false)?;
+                }
+            }
+        }
+
+        // drift loads the oracles, then the spot markets, then the perp markets
+        let mut accounts: Vec<AccountMeta> = oracles
+            .into_values()
+            .chain(spot_markets.into_values())
+            .chain(perp_markets.into_values())
+            .collect();
+
+        if let Some(referrer_info) = referrer_info {
+            accounts.push(AccountMeta::new(referrer_info.referrer(), false));
+            accounts.push(AccountMeta::new(referrer_info.referrer_stats(), false));
+        }
+
+        if is_spot {
+            for market_index in [market_index, QUOTE_SPOT_MARKET_INDEX] {
+                let spot_market = program_data
+                    .spot_market_config_by_index(market_index)
+                    .ok_or_else(|| {
+                        JitError::Sdk(format!("spot market {market_index} not found"))
+                    })?;
+                accounts.push(AccountMeta::new_readonly(spot_market.vault, false));
+            }
+        }
+
+        Ok(accounts)
+    }
+}
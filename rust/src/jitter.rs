@@ -11,7 +11,6 @@ use async_trait::async_trait;
 use dashmap::DashMap;
 use drift_rs::{
     auction_subscriber::{AuctionSubscriber, AuctionSubscriberConfig},
-    jit_client::{ComputeBudgetParams, JitIxParams, JitProxyClient, JitTakerParams},
     slot_subscriber::SlotSubscriber,
     swift_order_subscriber::SignedOrderInfo,
     types::{
@@ -25,7 +24,10 @@ use futures::StreamExt;
 use solana_sdk::signature::Signature;
 use tokio::task::JoinHandle;
 
-use crate::types::{JitError, JitResult};
+use crate::{
+    jit_client::{JitIxParams, JitProxyClient, JitTakerParams},
+    types::{ComputeBudgetParams, JitError, JitResult},
+};
 
 pub type ExcludeAuctionFn = dyn Fn(&User, &String, Order) -> bool + Send + Sync;
 
@@ -144,7 +146,8 @@ impl Jitter {
     // Subscribe to auction events and start listening for them
     pub async fn subscribe(self: Arc<Self>, url: String) -> JitResult<AuctionSubscriber> {
         // start swift order subscriber
-        let markets = self.drift_client.get_all_perp_market_ids();
+        let mut markets = self.drift_client.get_all_perp_market_ids();
+        markets.extend(self.drift_client.get_all_spot_market_ids());
         let mut swift_order_stream = self
             .drift_client
             .subscribe_swift_orders(&markets)
@@ -361,67 +364,71 @@ impl Jitter {
             self.drift_client.get_user_stats(taker_authority),
         )?;
 
-        match order.market_type {
-            MarketType::Spot => {
-                log::warn!("spot market swift unimplemented");
-                return Ok(());
-            }
-            MarketType::Perp => {
-                if let Some(param) = self.perp_params.get(&order.market_index) {
-                    let perp_market = self
-                        .drift_client
+        let param = match order.market_type {
+            MarketType::Perp => self.perp_params.get(&order.market_index),
+            MarketType::Spot => self.spot_params.get(&order.market_index),
+        };
+
+        if let Some(param) = param {
+            let min_order_size = match order.market_type {
+                MarketType::Perp => {
+                    self.drift_client
                         .program_data()
                         .perp_market_config_by_index(order.market_index)
-                        .unwrap();
-                    let remaining = order.base_asset_amount;
-                    let min_order_size = perp_market.amm.min_order_size;
+                        .unwrap()
+                        .amm
+                        .min_order_size
+                }
+                MarketType::Spot => {
+                    self.drift_client
+                        .program_data()
+                        .spot_market_config_by_index(order.market_index)
+                        .unwrap()
+                        .min_order_size
+                }
+            };
 
-                    if remaining < min_order_size {
-                        log::warn!(
-                                "Order filled within min order size\nRemaining: {}\nMinimum order size: {}",
-                                remaining,
-                                min_order_size
-                            );
-                        return Ok(());
-                    }
+            let remaining = order.base_asset_amount;
 
-                    if (remaining as i128) < param.min_position.into() {
-                        log::warn!(
-                            "Order filled within min position\nRemaining: {}\nMin position: {}",
-                            remaining,
-                            param.min_position
-                        );
-                        return Ok(());
-                    }
+            if remaining < min_order_size {
+                log::warn!(
+                    "Order filled within min order size\nRemaining: {}\nMinimum order size: {}",
+                    remaining,
+                    min_order_size
+                );
+                return Ok(());
+            }
 
-                    let jitter = Arc::clone(&self.jitter);
-                    let order_signature = order_sig.clone();
-                    let referrer_info = ReferrerInfo::get_referrer_info(taker_stats);
-                    let jit_ix_params = param.clone();
-
-                    let ongoing_auction = tokio::spawn(async move {
-                        let _ = jitter
-                            .try_swift_fill(
-                                &signed_order_info,
-                                order_signature,
-                                &JitTakerParams::new(
-                                    taker_pubkey,
-                                    taker_stats_key,
-                                    taker,
-                                    referrer_info,
-                                ),
-                                &jit_ix_params,
-                            )
-                            .await;
-                    });
-
-                    self.ongoing_auctions.insert(order_sig, ongoing_auction);
-                } else {
-                    log::warn!("Jitter not listening to {}", order.market_index);
-                    return Ok(());
-                }
+            if (remaining as i128) < param.min_position.into() {
+                log::warn!(
+                    "Order filled within min position\nRemaining: {}\nMin position: {}",
+                    remaining,
+                    param.min_position
+                );
+                return Ok(());
             }
+
+            let jitter = Arc::clone(&self.jitter);
+            let order_signature = order_sig.clone();
+            let referrer_info = ReferrerInfo::get_referrer_info(taker_stats);
+            let jit_ix_params = param.clone();
+
+            let ongoing_auction = tokio::spawn(async move {
+                let _ = jitter
+                    .try_swift_fill(
+                        &signed_order_info,
+                        order_signature,
+                        &JitTakerParams::new(taker_pubkey, taker_stats_key, taker, referrer_info),
+                        &jit_ix_params,
+                    )
+                    .await;
+            });
+
+            self.ongoing_auctions.insert(order_sig, ongoing_auction);
+        } else {
+            log::warn!("Jitter not listening to {}", order.market_index);
         }
+
         Ok(())
     }
 
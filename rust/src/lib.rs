@@ -1,3 +1,4 @@
-pub use drift_rs::jit_client::JitProxyClient;
+pub use jit_client::JitProxyClient;
+pub mod jit_client;
 pub mod jitter;
 pub mod types;
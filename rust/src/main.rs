@@ -11,16 +11,20 @@ use std::env;
 
 use drift_rs::{
     event_subscriber::RpcClient,
-    jit_client::{ComputeBudgetParams, JitIxParams, PriceType},
     types::{CommitmentConfig, Context, RpcSendTransactionConfig},
     utils::get_ws_url,
     DriftClient, Wallet,
 };
 
+pub mod jit_client;
 pub mod jitter;
 pub mod types;
 
-use crate::jitter::Jitter;
+use crate::{
+    jit_client::{JitIxParams, PriceType},
+    jitter::Jitter,
+    types::ComputeBudgetParams,
+};
 
 #[tokio::main]
 async fn main() {
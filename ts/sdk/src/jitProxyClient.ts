@@ -5,6 +5,7 @@ import {
 	isVariant,
 	MakerInfo,
 	MarketType,
+	Order,
 	PostOnlyParams,
 	QUOTE_SPOT_MARKET_INDEX,
 	ReferrerInfo,
@@ -16,8 +17,10 @@ import {
 } from '@drift-labs/sdk';
 import { IDL, JitProxy } from './types/jit_proxy';
 import {
+	AccountMeta,
 	ComputeBudgetProgram,
 	PublicKey,
+	SystemProgram,
 	TransactionInstruction,
 	TransactionMessage,
 	VersionedTransaction,
@@ -27,6 +30,45 @@ import { TxSigAndSlot } from '@drift-labs/sdk';
 
 export const DEFAULT_CU_LIMIT = 1_400_000;
 
+export function getMakerControlPublicKey(
+	programId: PublicKey,
+	user: PublicKey
+): PublicKey {
+	return PublicKey.findProgramAddressSync(
+		[Buffer.from('maker_control'), user.toBuffer()],
+		programId
+	)[0];
+}
+
+export function getRiskLedgerPublicKey(
+	programId: PublicKey,
+	user: PublicKey
+): PublicKey {
+	return PublicKey.findProgramAddressSync(
+		[Buffer.from('risk_ledger'), user.toBuffer()],
+		programId
+	)[0];
+}
+
+export function getMakerConfigPublicKey(
+	programId: PublicKey,
+	user: PublicKey,
+	marketType: MarketType,
+	marketIndex: number
+): PublicKey {
+	const marketIndexBuffer = Buffer.alloc(2);
+	marketIndexBuffer.writeUInt16LE(marketIndex);
+	return PublicKey.findProgramAddressSync(
+		[
+			Buffer.from('maker_config'),
+			user.toBuffer(),
+			Buffer.from([isVariant(marketType, 'perp') ? 0 : 1]),
+			marketIndexBuffer,
+		],
+		programId
+	)[0];
+}
+
 export type JitIxParams = {
 	takerKey: PublicKey;
 	takerStatsKey: PublicKey;
@@ -40,6 +82,82 @@ export type JitIxParams = {
 	priceType?: PriceType;
 	referrerInfo?: ReferrerInfo;
 	subAccountId?: number;
+	/**
+	 * maker control pda, derived from the user by default. the derived account must exist,
+	 * pass null to fill without a maker control
+	 */
+	makerControl?: PublicKey | null;
+	maxFillSize?: BN;
+	minFillSize?: BN;
+	priceLadder?: PriceTier[];
+	skewPerBase?: BN;
+	maxOracleConfidenceBps?: BN;
+	maxOracleDelaySlots?: BN;
+	validUntilSlot?: BN;
+	maxTs?: BN;
+	expectedOraclePrice?: BN;
+	maxOracleDeviationBps?: BN;
+	minEdgeBps?: BN;
+	feeAware?: boolean;
+	fillMode?: FillMode;
+	reduceOnly?: boolean;
+};
+
+export type JitBatchEntryIxParams = Omit<
+	JitIxParams,
+	'subAccountId' | 'makerControl'
+>;
+
+export type JitBatchIxParams = {
+	entries: JitBatchEntryIxParams[];
+	bestEffort?: boolean;
+	subAccountId?: number;
+	/**
+	 * maker control pda, derived from the user by default. the derived account must exist,
+	 * pass null to fill without a maker control
+	 */
+	makerControl?: PublicKey | null;
+};
+
+export type MakerConfigIxParams = {
+	maxPosition: BN;
+	minPosition: BN;
+	bid: BN;
+	ask: BN;
+	priceType?: PriceType;
+	postOnly?: PostOnlyParams | null;
+};
+
+export type JitWithConfigIxParams = {
+	takerKey: PublicKey;
+	takerStatsKey: PublicKey;
+	taker: UserAccount;
+	takerOrderId: number;
+	referrerInfo?: ReferrerInfo;
+	subAccountId?: number;
+	/**
+	 * maker control pda, derived from the user by default. the derived account must exist,
+	 * pass null to fill without a maker control
+	 */
+	makerControl?: PublicKey | null;
+};
+
+export type MakerControlIxParams = {
+	paused: boolean;
+	pausedPerpMarkets: number[];
+	pausedSpotMarkets: number[];
+};
+
+export type RiskLedgerIxParams = {
+	windowDuration: BN;
+	maxWindowNotional: BN;
+	maxWindowLoss: BN;
+};
+
+export type PriceTier = {
+	sizeThreshold: BN;
+	bid: BN;
+	ask: BN;
 };
 
 export type JitSignedMsgIxParams = JitIxParams & {
@@ -52,6 +170,9 @@ export type JitSignedMsgIxParams = JitIxParams & {
 export class PriceType {
 	static readonly LIMIT = { limit: {} };
 	static readonly ORACLE = { oracle: {} };
+	static readonly ORACLE_BPS = { oracleBps: {} };
+	static readonly AMM = { amm: {} };
+	static readonly ORACLE_TWAP = { oracleTwap: {} };
 }
 
 /**
@@ -59,16 +180,50 @@ export class PriceType {
  */
 export function validatePriceType(
 	priceType: PriceType
-): typeof PriceType.LIMIT | typeof PriceType.ORACLE {
+):
+	| typeof PriceType.LIMIT
+	| typeof PriceType.ORACLE
+	| typeof PriceType.ORACLE_BPS
+	| typeof PriceType.AMM
+	| typeof PriceType.ORACLE_TWAP {
 	if (isVariant(priceType, 'limit')) {
 		return PriceType.LIMIT;
 	}
 	if (isVariant(priceType, 'oracle')) {
 		return PriceType.ORACLE;
 	}
+	if (isVariant(priceType, 'oracleBps')) {
+		return PriceType.ORACLE_BPS;
+	}
+	if (isVariant(priceType, 'amm')) {
+		return PriceType.AMM;
+	}
+	if (isVariant(priceType, 'oracleTwap')) {
+		return PriceType.ORACLE_TWAP;
+	}
 	throw new Error('Invalid price type');
 }
 
+export class FillMode {
+	static readonly PARTIAL = { partial: {} };
+	static readonly FILL_OR_KILL = { fillOrKill: {} };
+}
+
+/**
+ * Validates the fill mode and returns the corresponding enum value. (required for type checking because of how anchor handles recursive types in IDL)
+ */
+export function validateFillMode(
+	fillMode: FillMode
+): typeof FillMode.PARTIAL | typeof FillMode.FILL_OR_KILL {
+	if (isVariant(fillMode, 'partial')) {
+		return FillMode.PARTIAL;
+	}
+	if (isVariant(fillMode, 'fillOrKill')) {
+		return FillMode.FILL_OR_KILL;
+	}
+	throw new Error('Invalid fill mode');
+}
+
 /**
  * Validates the PostOnlyParams and returns the corresponding enum value. (required for type checking because of how anchor handles recursive types in IDL)
  */
@@ -112,6 +267,68 @@ export function validateMarketType(
 	throw new Error('Invalid market type');
 }
 
+export class ArbSizing {
+	static readonly QUOTE_ASSET = { quoteAsset: {} };
+	static readonly FREE_COLLATERAL = { freeCollateral: {} };
+}
+
+/**
+ * Validates the arb sizing and returns the corresponding enum value. (required for type checking because of how anchor handles recursive types in IDL)
+ */
+export function validateArbSizing(
+	sizing: ArbSizing
+): typeof ArbSizing.QUOTE_ASSET | typeof ArbSizing.FREE_COLLATERAL {
+	if (isVariant(sizing, 'quoteAsset')) {
+		return ArbSizing.QUOTE_ASSET;
+	}
+	if (isVariant(sizing, 'freeCollateral')) {
+		return ArbSizing.FREE_COLLATERAL;
+	}
+	throw new Error('Invalid arb sizing');
+}
+
+export type ArbPerpIxParams = {
+	makerInfos: MakerInfo[];
+	marketIndex: number;
+	minProfitQuote?: BN;
+	maxBaseAssetAmount?: BN;
+	sizing?: ArbSizing;
+	referrerInfo?: ReferrerInfo;
+	/**
+	 * maker control pda, derived from the user by default. the derived account must exist,
+	 * pass null to fill without a maker control
+	 */
+	makerControl?: PublicKey | null;
+};
+
+export type ArbSpotIxParams = {
+	makerInfos: MakerInfo[];
+	marketIndex: number;
+	baseTolerance?: BN;
+	referrerInfo?: ReferrerInfo;
+	/**
+	 * maker control pda, derived from the user by default. the derived account must exist,
+	 * pass null to fill without a maker control
+	 */
+	makerControl?: PublicKey | null;
+};
+
+export type ArbBasisIxParams = {
+	makerInfos: MakerInfo[];
+	perpMarketIndex: number;
+	spotMarketIndex: number;
+	minSpread?: BN;
+	maxBaseAssetAmount: BN;
+	deltaTolerance?: BN;
+	minProfitQuote?: BN;
+	referrerInfo?: ReferrerInfo;
+	/**
+	 * maker control pda, derived from the user by default. the derived account must exist,
+	 * pass null to fill without a maker control
+	 */
+	makerControl?: PublicKey | null;
+};
+
 export type OrderConstraint = {
 	maxPosition: BN;
 	minPosition: BN;
@@ -188,10 +405,146 @@ export class JitProxyClient {
 		return await this.driftClient.txSender.sendVersionedTransaction(tx);
 	}
 
-	public async getJitIx({
-		takerKey,
-		takerStatsKey,
-		taker,
+	public async getJitIx(params: JitIxParams): Promise<TransactionInstruction> {
+		const {
+			takerKey,
+			takerStatsKey,
+			taker,
+			takerOrderId,
+			makerControl,
+		} = params;
+		const subAccountId =
+			params.subAccountId !== undefined
+				? params.subAccountId
+				: this.driftClient.activeSubAccountId;
+		const order = taker.orders.find((order) => order.orderId === takerOrderId);
+		const remainingAccounts = this.getJitRemainingAccounts(
+			taker,
+			order,
+			subAccountId,
+			params.referrerInfo
+		);
+
+		const user = await this.driftClient.getUserAccountPublicKey(subAccountId);
+
+		return this.program.methods
+			.jit(this.getJitParams(params))
+			.accounts({
+				taker: takerKey,
+				takerStats: takerStatsKey,
+				state: await this.driftClient.getStatePublicKey(),
+				user,
+				userStats: this.driftClient.getUserStatsAccountPublicKey(),
+				driftProgram: this.driftClient.program.programId,
+				makerControl: this.getMakerControl(user, makerControl),
+				riskLedger: getRiskLedgerPublicKey(this.program.programId, user),
+			})
+			.remainingAccounts(remainingAccounts)
+			.instruction();
+	}
+
+	public async jitBatch(
+		params: JitBatchIxParams,
+		txParams?: TxParams
+	): Promise<TxSigAndSlot> {
+		const ix = await this.getJitBatchIx(params);
+		const tx = await this.driftClient.buildTransaction([ix], txParams);
+		return await this.driftClient.sendTransaction(tx);
+	}
+
+	/**
+	 * The market and oracle accounts for every entry go first, followed by each entry's extra
+	 * accounts (referrer, spot vaults or builder escrow) and then the taker and taker stats
+	 * accounts. Entries for the same taker share its accounts.
+	 */
+	public async getJitBatchIx({
+		entries,
+		bestEffort = false,
+		subAccountId,
+		makerControl,
+	}: JitBatchIxParams): Promise<TransactionInstruction> {
+		subAccountId =
+			subAccountId !== undefined
+				? subAccountId
+				: this.driftClient.activeSubAccountId;
+
+		const orders = entries.map((entry) =>
+			entry.taker.orders.find((order) => order.orderId === entry.takerOrderId)
+		);
+
+		const writablePerpMarketIndexes = new Set<number>();
+		const writableSpotMarketIndexes = new Set<number>();
+		for (const order of orders) {
+			if (isVariant(order.marketType, 'spot')) {
+				writableSpotMarketIndexes.add(order.marketIndex);
+				writableSpotMarketIndexes.add(QUOTE_SPOT_MARKET_INDEX);
+			} else {
+				writablePerpMarketIndexes.add(order.marketIndex);
+			}
+		}
+
+		const remainingAccounts = this.driftClient.getRemainingAccounts({
+			userAccounts: [
+				...entries.map((entry) => entry.taker),
+				this.driftClient.getUserAccount(subAccountId),
+			],
+			writablePerpMarketIndexes: Array.from(writablePerpMarketIndexes),
+			writableSpotMarketIndexes: Array.from(writableSpotMarketIndexes),
+		});
+
+		const extraAccounts = entries.map((entry, i) => {
+			const accounts = this.getJitExtraAccounts(
+				entry.taker,
+				orders[i],
+				entry.referrerInfo
+			);
+			const extraAccountsIndex = remainingAccounts.length;
+			remainingAccounts.push(...accounts);
+			return { extraAccountsIndex, extraAccountsLen: accounts.length };
+		});
+
+		const takerIndexes = new Map<string, number>();
+		const jitBatchEntries = entries.map((entry, i) => {
+			let takerIndex = takerIndexes.get(entry.takerKey.toBase58());
+			if (takerIndex === undefined) {
+				takerIndex = remainingAccounts.length;
+				takerIndexes.set(entry.takerKey.toBase58(), takerIndex);
+				remainingAccounts.push({
+					pubkey: entry.takerKey,
+					isWritable: true,
+					isSigner: false,
+				});
+				remainingAccounts.push({
+					pubkey: entry.takerStatsKey,
+					isWritable: true,
+					isSigner: false,
+				});
+			}
+
+			return {
+				takerIndex,
+				params: this.getJitParams(entry),
+				...extraAccounts[i],
+			};
+		});
+
+		const user = await this.driftClient.getUserAccountPublicKey(subAccountId);
+
+		return this.program.methods
+			.jitBatch({ entries: jitBatchEntries, bestEffort })
+			.accounts({
+				state: await this.driftClient.getStatePublicKey(),
+				user,
+				userStats: this.driftClient.getUserStatsAccountPublicKey(),
+				driftProgram: this.driftClient.program.programId,
+				makerControl: this.getMakerControl(user, makerControl),
+				riskLedger: getRiskLedgerPublicKey(this.program.programId, user),
+			})
+			.remainingAccounts(remainingAccounts)
+			.instruction();
+	}
+
+	private getJitParams({
 		takerOrderId,
 		maxPosition,
 		minPosition,
@@ -199,14 +552,52 @@ export class JitProxyClient {
 		ask,
 		postOnly = null,
 		priceType = PriceType.LIMIT,
-		referrerInfo,
-		subAccountId,
-	}: JitIxParams): Promise<TransactionInstruction> {
-		subAccountId =
-			subAccountId !== undefined
-				? subAccountId
-				: this.driftClient.activeSubAccountId;
-		const order = taker.orders.find((order) => order.orderId === takerOrderId);
+		maxFillSize = null,
+		minFillSize = null,
+		priceLadder = [],
+		skewPerBase = null,
+		maxOracleConfidenceBps = null,
+		maxOracleDelaySlots = null,
+		validUntilSlot = null,
+		maxTs = null,
+		expectedOraclePrice = null,
+		maxOracleDeviationBps = null,
+		minEdgeBps = null,
+		feeAware = false,
+		fillMode = FillMode.PARTIAL,
+		reduceOnly = false,
+	}: JitBatchEntryIxParams) {
+		return {
+			takerOrderId,
+			maxPosition,
+			minPosition,
+			bid,
+			ask,
+			postOnly: validatePostOnlyParams(postOnly),
+			priceType: validatePriceType(priceType),
+			maxFillSize,
+			minFillSize,
+			priceLadder,
+			skewPerBase,
+			maxOracleConfidenceBps,
+			maxOracleDelaySlots,
+			validUntilSlot,
+			maxTs,
+			expectedOraclePrice,
+			maxOracleDeviationBps,
+			minEdgeBps,
+			feeAware,
+			fillMode: validateFillMode(fillMode),
+			reduceOnly,
+		};
+	}
+
+	private getJitRemainingAccounts(
+		taker: UserAccount,
+		order: Order,
+		subAccountId: number,
+		referrerInfo?: ReferrerInfo
+	): AccountMeta[] {
 		const remainingAccounts = this.driftClient.getRemainingAccounts({
 			userAccounts: [taker, this.driftClient.getUserAccount(subAccountId)],
 			writableSpotMarketIndexes: isVariant(order.marketType, 'spot')
@@ -216,14 +607,26 @@ export class JitProxyClient {
 				? [order.marketIndex]
 				: [],
 		});
+		remainingAccounts.push(
+			...this.getJitExtraAccounts(taker, order, referrerInfo)
+		);
+		return remainingAccounts;
+	}
+
+	private getJitExtraAccounts(
+		taker: UserAccount,
+		order: Order,
+		referrerInfo?: ReferrerInfo
+	): AccountMeta[] {
+		const accounts: AccountMeta[] = [];
 
 		if (referrerInfo) {
-			remainingAccounts.push({
+			accounts.push({
 				pubkey: referrerInfo.referrer,
 				isWritable: true,
 				isSigner: false,
 			});
-			remainingAccounts.push({
+			accounts.push({
 				pubkey: referrerInfo.referrerStats,
 				isWritable: true,
 				isSigner: false,
@@ -231,19 +634,19 @@ export class JitProxyClient {
 		}
 
 		if (isVariant(order.marketType, 'spot')) {
-			remainingAccounts.push({
+			accounts.push({
 				pubkey: this.driftClient.getSpotMarketAccount(order.marketIndex).vault,
 				isWritable: false,
 				isSigner: false,
 			});
-			remainingAccounts.push({
+			accounts.push({
 				pubkey: this.driftClient.getQuoteSpotMarketAccount().vault,
 				isWritable: false,
 				isSigner: false,
 			});
 		} else {
 			if (hasBuilder(order)) {
-				remainingAccounts.push({
+				accounts.push({
 					pubkey: getRevenueShareEscrowAccountPublicKey(
 						this.program.programId,
 						taker.authority
@@ -254,28 +657,7 @@ export class JitProxyClient {
 			}
 		}
 
-		const jitParams = {
-			takerOrderId,
-			maxPosition,
-			minPosition,
-			bid,
-			ask,
-			postOnly: validatePostOnlyParams(postOnly),
-			priceType: validatePriceType(priceType),
-		};
-
-		return this.program.methods
-			.jit(jitParams)
-			.accounts({
-				taker: takerKey,
-				takerStats: takerStatsKey,
-				state: await this.driftClient.getStatePublicKey(),
-				user: await this.driftClient.getUserAccountPublicKey(subAccountId),
-				userStats: this.driftClient.getUserStatsAccountPublicKey(),
-				driftProgram: this.driftClient.program.programId,
-			})
-			.remainingAccounts(remainingAccounts)
-			.instruction();
+		return accounts;
 	}
 
 	public async getJitSignedMsgIx({
@@ -294,6 +676,14 @@ export class JitProxyClient {
 		marketIndex,
 		signedMsgOrderParams,
 		authorityToUse,
+		makerControl,
+		reduceOnly = false,
+		maxOracleConfidenceBps = null,
+		maxOracleDelaySlots = null,
+		validUntilSlot = null,
+		maxTs = null,
+		expectedOraclePrice = null,
+		maxOracleDeviationBps = null,
 	}: JitSignedMsgIxParams): Promise<TransactionInstruction> {
 		subAccountId =
 			subAccountId !== undefined
@@ -350,8 +740,17 @@ export class JitProxyClient {
 			ask,
 			postOnly: validatePostOnlyParams(postOnly),
 			priceType: validatePriceType(priceType),
+			maxOracleConfidenceBps,
+			maxOracleDelaySlots,
+			validUntilSlot,
+			maxTs,
+			expectedOraclePrice,
+			maxOracleDeviationBps,
+			reduceOnly,
 		};
 
+		const user = await this.driftClient.getUserAccountPublicKey(subAccountId);
+
 		return this.program.methods
 			.jitSignedMsg(jitSignedMsgParams)
 			.accounts({
@@ -363,9 +762,11 @@ export class JitProxyClient {
 				),
 				authority: this.driftClient.wallet.payer.publicKey,
 				state: await this.driftClient.getStatePublicKey(),
-				user: await this.driftClient.getUserAccountPublicKey(subAccountId),
+				user,
 				userStats: this.driftClient.getUserStatsAccountPublicKey(),
 				driftProgram: this.driftClient.program.programId,
+				makerControl: this.getMakerControl(user, makerControl),
+				riskLedger: getRiskLedgerPublicKey(this.program.programId, user),
 			})
 			.remainingAccounts(remainingAccounts)
 			.instruction();
@@ -416,10 +817,7 @@ export class JitProxyClient {
 	}
 
 	public async arbPerp(
-		params: {
-			makerInfos: MakerInfo[];
-			marketIndex: number;
-		},
+		params: ArbPerpIxParams,
 		txParams?: TxParams
 	): Promise<TxSigAndSlot> {
 		const ix = await this.getArbPerpIx(params);
@@ -430,12 +828,141 @@ export class JitProxyClient {
 	public async getArbPerpIx({
 		makerInfos,
 		marketIndex,
+		minProfitQuote = new BN(0),
+		maxBaseAssetAmount = null,
+		sizing = ArbSizing.QUOTE_ASSET,
 		referrerInfo,
-	}: {
-		makerInfos: MakerInfo[];
-		marketIndex: number;
-		referrerInfo?: ReferrerInfo;
-	}): Promise<TransactionInstruction> {
+		makerControl,
+	}: ArbPerpIxParams): Promise<TransactionInstruction> {
+		const remainingAccounts = this.getArbRemainingAccounts(
+			makerInfos,
+			referrerInfo,
+			{ writablePerpMarketIndexes: [marketIndex] }
+		);
+
+		const arbPerpParams = {
+			minProfitQuote,
+			maxBaseAssetAmount,
+			sizing: validateArbSizing(sizing),
+		};
+
+		const user = await this.driftClient.getUserAccountPublicKey();
+
+		return this.program.methods
+			.arbPerp(marketIndex, arbPerpParams)
+			.accounts({
+				state: await this.driftClient.getStatePublicKey(),
+				user,
+				userStats: this.driftClient.getUserStatsAccountPublicKey(),
+				driftProgram: this.driftClient.program.programId,
+				makerControl: this.getMakerControl(user, makerControl),
+				riskLedger: getRiskLedgerPublicKey(this.program.programId, user),
+			})
+			.remainingAccounts(remainingAccounts)
+			.instruction();
+	}
+
+	public async arbSpot(
+		params: ArbSpotIxParams,
+		txParams?: TxParams
+	): Promise<TxSigAndSlot> {
+		const ix = await this.getArbSpotIx(params);
+		const tx = await this.driftClient.buildTransaction([ix], txParams);
+		return await this.driftClient.sendTransaction(tx);
+	}
+
+	public async getArbSpotIx({
+		makerInfos,
+		marketIndex,
+		baseTolerance = new BN(0),
+		referrerInfo,
+		makerControl,
+	}: ArbSpotIxParams): Promise<TransactionInstruction> {
+		const remainingAccounts = this.getArbRemainingAccounts(
+			makerInfos,
+			referrerInfo,
+			{ writableSpotMarketIndexes: [marketIndex, QUOTE_SPOT_MARKET_INDEX] }
+		);
+
+		const user = await this.driftClient.getUserAccountPublicKey();
+
+		return this.program.methods
+			.arbSpot(marketIndex, { baseTolerance })
+			.accounts({
+				state: await this.driftClient.getStatePublicKey(),
+				user,
+				userStats: this.driftClient.getUserStatsAccountPublicKey(),
+				driftProgram: this.driftClient.program.programId,
+				makerControl: this.getMakerControl(user, makerControl),
+				riskLedger: getRiskLedgerPublicKey(this.program.programId, user),
+			})
+			.remainingAccounts(remainingAccounts)
+			.instruction();
+	}
+
+	public async arbBasis(
+		params: ArbBasisIxParams,
+		txParams?: TxParams
+	): Promise<TxSigAndSlot> {
+		const ix = await this.getArbBasisIx(params);
+		const tx = await this.driftClient.buildTransaction([ix], txParams);
+		return await this.driftClient.sendTransaction(tx);
+	}
+
+	public async getArbBasisIx({
+		makerInfos,
+		perpMarketIndex,
+		spotMarketIndex,
+		minSpread = new BN(0),
+		maxBaseAssetAmount,
+		deltaTolerance = new BN(0),
+		minProfitQuote = new BN(0),
+		referrerInfo,
+		makerControl,
+	}: ArbBasisIxParams): Promise<TransactionInstruction> {
+		const remainingAccounts = this.getArbRemainingAccounts(
+			makerInfos,
+			referrerInfo,
+			{
+				writablePerpMarketIndexes: [perpMarketIndex],
+				writableSpotMarketIndexes: [spotMarketIndex, QUOTE_SPOT_MARKET_INDEX],
+			}
+		);
+
+		const arbBasisParams = {
+			minSpread,
+			maxBaseAssetAmount,
+			deltaTolerance,
+			minProfitQuote,
+		};
+
+		const user = await this.driftClient.getUserAccountPublicKey();
+
+		return this.program.methods
+			.arbBasis(perpMarketIndex, spotMarketIndex, arbBasisParams)
+			.accounts({
+				state: await this.driftClient.getStatePublicKey(),
+				user,
+				userStats: this.driftClient.getUserStatsAccountPublicKey(),
+				driftProgram: this.driftClient.program.programId,
+				makerControl: this.getMakerControl(user, makerControl),
+				riskLedger: getRiskLedgerPublicKey(this.program.programId, user),
+			})
+			.remainingAccounts(remainingAccounts)
+			.instruction();
+	}
+
+	private getArbRemainingAccounts(
+		makerInfos: MakerInfo[],
+		referrerInfo: ReferrerInfo | undefined,
+		{
+			writablePerpMarketIndexes = [],
+			writableSpotMarketIndexes = [],
+		}: {
+			writablePerpMarketIndexes?: number[];
+			writableSpotMarketIndexes?: number[];
+		}
+	): AccountMeta[] {
 		const userAccounts = [this.driftClient.getUserAccount()];
 		for (const makerInfo of makerInfos) {
 			userAccounts.push(makerInfo.makerUserAccount);
@@ -443,7 +970,8 @@ export class JitProxyClient {
 
 		const remainingAccounts = this.driftClient.getRemainingAccounts({
 			userAccounts,
-			writablePerpMarketIndexes: [marketIndex],
+			writablePerpMarketIndexes,
+			writableSpotMarketIndexes,
 		});
 
 		for (const makerInfo of makerInfos) {
@@ -478,15 +1006,232 @@ export class JitProxyClient {
 			}
 		}
 
+		return remainingAccounts;
+	}
+
+	public async getInitMakerConfigIx(
+		marketType: MarketType,
+		marketIndex: number,
+		params: MakerConfigIxParams,
+		subAccountId?: number
+	): Promise<TransactionInstruction> {
+		const user = await this.driftClient.getUserAccountPublicKey(subAccountId);
+
 		return this.program.methods
-			.arbPerp(marketIndex)
+			.initMakerConfig(
+				validateMarketType(marketType),
+				marketIndex,
+				this.getMakerConfigParams(params)
+			)
 			.accounts({
-				state: await this.driftClient.getStatePublicKey(),
-				user: await this.driftClient.getUserAccountPublicKey(),
-				userStats: this.driftClient.getUserStatsAccountPublicKey(),
-				driftProgram: this.driftClient.program.programId,
+				user,
+				authority: this.driftClient.wallet.publicKey,
+				makerConfig: getMakerConfigPublicKey(
+					this.program.programId,
+					user,
+					marketType,
+					marketIndex
+				),
+				systemProgram: SystemProgram.programId,
+			})
+			.instruction();
+	}
+
+	public async getUpdateMakerConfigIx(
+		marketType: MarketType,
+		marketIndex: number,
+		params: MakerConfigIxParams,
+		subAccountId?: number
+	): Promise<TransactionInstruction> {
+		const user = await this.driftClient.getUserAccountPublicKey(subAccountId);
+
+		return this.program.methods
+			.updateMakerConfig(this.getMakerConfigParams(params))
+			.accounts({
+				user,
+				authority: this.driftClient.wallet.publicKey,
+				makerConfig: getMakerConfigPublicKey(
+					this.program.programId,
+					user,
+					marketType,
+					marketIndex
+				),
+			})
+			.instruction();
+	}
+
+	public async getCloseMakerConfigIx(
+		marketType: MarketType,
+		marketIndex: number,
+		subAccountId?: number
+	): Promise<TransactionInstruction> {
+		const user = await this.driftClient.getUserAccountPublicKey(subAccountId);
+
+		return this.program.methods
+			.closeMakerConfig()
+			.accounts({
+				user,
+				authority: this.driftClient.wallet.publicKey,
+				makerConfig: getMakerConfigPublicKey(
+					this.program.programId,
+					user,
+					marketType,
+					marketIndex
+				),
+			})
+			.instruction();
+	}
+
+	public async jitWithConfig(
+		params: JitWithConfigIxParams,
+		txParams?: TxParams
+	): Promise<TxSigAndSlot> {
+		const ix = await this.getJitWithConfigIx(params);
+		const tx = await this.driftClient.buildTransaction([ix], txParams);
+		return await this.driftClient.sendTransaction(tx);
+	}
+
+	/**
+	 * Fills the taker order with the params stored in the maker config of the order's market
+	 */
+	public async getJitWithConfigIx({
+		takerKey,
+		takerStatsKey,
+		taker,
+		takerOrderId,
+		referrerInfo,
+		subAccountId,
+		makerControl,
+	}: JitWithConfigIxParams): Promise<TransactionInstruction> {
+		subAccountId =
+			subAccountId !== undefined
+				? subAccountId
+				: this.driftClient.activeSubAccountId;
+		const order = taker.orders.find((order) => order.orderId === takerOrderId);
+		const remainingAccounts = this.getJitRemainingAccounts(
+			taker,
+			order,
+			subAccountId,
+			referrerInfo
+		);
+
+		const user = await this.driftClient.getUserAccountPublicKey(subAccountId);
+
+		return this.program.methods
+			.jitWithConfig(takerOrderId)
+			.accounts({
+				jit: {
+					taker: takerKey,
+					takerStats: takerStatsKey,
+					state: await this.driftClient.getStatePublicKey(),
+					user,
+					userStats: this.driftClient.getUserStatsAccountPublicKey(),
+					authority: this.driftClient.wallet.publicKey,
+					driftProgram: this.driftClient.program.programId,
+					makerControl: this.getMakerControl(user, makerControl),
+					riskLedger: getRiskLedgerPublicKey(this.program.programId, user),
+				},
+				makerConfig: getMakerConfigPublicKey(
+					this.program.programId,
+					user,
+					order.marketType,
+					order.marketIndex
+				),
 			})
 			.remainingAccounts(remainingAccounts)
 			.instruction();
 	}
+
+	private getMakerConfigParams({
+		maxPosition,
+		minPosition,
+		bid,
+		ask,
+		priceType = PriceType.LIMIT,
+		postOnly = null,
+	}: MakerConfigIxParams) {
+		return {
+			maxPosition,
+			minPosition,
+			bid,
+			ask,
+			priceType: validatePriceType(priceType),
+			postOnly: postOnly === null ? null : validatePostOnlyParams(postOnly),
+		};
+	}
+
+	public async getInitMakerControlIx(
+		subAccountId?: number
+	): Promise<TransactionInstruction> {
+		const user = await this.driftClient.getUserAccountPublicKey(subAccountId);
+
+		return this.program.methods
+			.initMakerControl()
+			.accounts({
+				user,
+				authority: this.driftClient.wallet.publicKey,
+				makerControl: getMakerControlPublicKey(this.program.programId, user),
+				systemProgram: SystemProgram.programId,
+			})
+			.instruction();
+	}
+
+	public async getUpdateMakerControlIx(
+		params: MakerControlIxParams,
+		subAccountId?: number
+	): Promise<TransactionInstruction> {
+		const user = await this.driftClient.getUserAccountPublicKey(subAccountId);
+
+		return this.program.methods
+			.updateMakerControl(params)
+			.accounts({
+				user,
+				authority: this.driftClient.wallet.publicKey,
+				makerControl: getMakerControlPublicKey(this.program.programId, user),
+			})
+			.instruction();
+	}
+
+
+	public async getInitRiskLedgerIx(
+		params: RiskLedgerIxParams,
+		subAccountId?: number
+	): Promise<TransactionInstruction> {
+		const user = await this.driftClient.getUserAccountPublicKey(subAccountId);
+
+		return this.program.methods
+			.initRiskLedger(params)
+			.accounts({
+				user,
+				authority: this.driftClient.wallet.publicKey,
+				riskLedger: getRiskLedgerPublicKey(this.program.programId, user),
+				systemProgram: SystemProgram.programId,
+			})
+			.instruction();
+	}
+
+	public async getUpdateRiskLedgerIx(
+		params: RiskLedgerIxParams,
+		subAccountId?: number
+	): Promise<TransactionInstruction> {
+		const user = await this.driftClient.getUserAccountPublicKey(subAccountId);
+
+		return this.program.methods
+			.updateRiskLedger(params)
+			.accounts({
+				user,
+				authority: this.driftClient.wallet.publicKey,
+				riskLedger: getRiskLedgerPublicKey(this.program.programId, user),
+			})
+			.instruction();
+	}
+
+	private getMakerControl(
+		user: PublicKey,
+		makerControl: PublicKey | null | undefined
+	): PublicKey | null {
+		return makerControl === undefined
+			? getMakerControlPublicKey(this.program.programId, user)
+			: makerControl;
+	}
 }
@@ -39,6 +39,17 @@ export type JitProxy = {
 					name: 'driftProgram';
 					isMut: false;
 					isSigner: false;
+				},
+				{
+					name: 'makerControl';
+					isMut: false;
+					isSigner: false;
+					isOptional: true;
+				},
+				{
+					name: 'riskLedger';
+					isMut: true;
+					isSigner: false;
 				}
 			];
 			args: [
@@ -92,6 +103,17 @@ export type JitProxy = {
 					name: 'driftProgram';
 					isMut: false;
 					isSigner: false;
+				},
+				{
+					name: 'makerControl';
+					isMut: false;
+					isSigner: false;
+					isOptional: true;
+				},
+				{
+					name: 'riskLedger';
+					isMut: true;
+					isSigner: false;
 				}
 			];
 			args: [
@@ -103,6 +125,55 @@ export type JitProxy = {
 				}
 			];
 		},
+		{
+			name: 'jitBatch';
+			accounts: [
+				{
+					name: 'state';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'user';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'userStats';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'makerControl';
+					isMut: false;
+					isSigner: false;
+					isOptional: true;
+				},
+				{
+					name: 'riskLedger';
+					isMut: true;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'params';
+					type: {
+						defined: 'JitBatchParams';
+					};
+				}
+			];
+		},
 		{
 			name: 'checkOrderConstraints';
 			accounts: [
@@ -150,107 +221,448 @@ export type JitProxy = {
 					name: 'driftProgram';
 					isMut: false;
 					isSigner: false;
+				},
+				{
+					name: 'makerControl';
+					isMut: false;
+					isSigner: false;
+					isOptional: true;
+				},
+				{
+					name: 'riskLedger';
+					isMut: true;
+					isSigner: false;
 				}
 			];
 			args: [
 				{
 					name: 'marketIndex';
 					type: 'u16';
+				},
+				{
+					name: 'params';
+					type: {
+						defined: 'ArbPerpParams';
+					};
 				}
 			];
-		}
-	];
-	types: [
+		},
 		{
-			name: 'OrderConstraint';
-			type: {
-				kind: 'struct';
-				fields: [
-					{
-						name: 'maxPosition';
-						type: 'i64';
-					},
-					{
-						name: 'minPosition';
-						type: 'i64';
-					},
-					{
-						name: 'marketIndex';
-						type: 'u16';
-					},
-					{
-						name: 'marketType';
-						type: {
-							defined: 'MarketType';
-						};
-					}
-				];
-			};
+			name: 'arbSpot';
+			accounts: [
+				{
+					name: 'state';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'user';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'userStats';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'makerControl';
+					isMut: false;
+					isSigner: false;
+					isOptional: true;
+				},
+				{
+					name: 'riskLedger';
+					isMut: true;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'marketIndex';
+					type: 'u16';
+				},
+				{
+					name: 'params';
+					type: {
+						defined: 'ArbSpotParams';
+					};
+				}
+			];
 		},
 		{
-			name: 'JitParams';
-			type: {
-				kind: 'struct';
-				fields: [
-					{
-						name: 'takerOrderId';
-						type: 'u32';
-					},
-					{
-						name: 'maxPosition';
-						type: 'i64';
-					},
-					{
-						name: 'minPosition';
-						type: 'i64';
-					},
-					{
-						name: 'bid';
-						type: 'i64';
-					},
-					{
-						name: 'ask';
-						type: 'i64';
-					},
-					{
-						name: 'priceType';
-						type: {
-							defined: 'PriceType';
-						};
-					},
-					{
-						name: 'postOnly';
-						type: {
-							option: {
-								defined: 'PostOnlyParam';
-							};
-						};
-					}
-				];
-			};
+			name: 'arbBasis';
+			accounts: [
+				{
+					name: 'state';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'user';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'userStats';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'makerControl';
+					isMut: false;
+					isSigner: false;
+					isOptional: true;
+				},
+				{
+					name: 'riskLedger';
+					isMut: true;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'perpMarketIndex';
+					type: 'u16';
+				},
+				{
+					name: 'spotMarketIndex';
+					type: 'u16';
+				},
+				{
+					name: 'params';
+					type: {
+						defined: 'ArbBasisParams';
+					};
+				}
+			];
 		},
 		{
-			name: 'JitSignedMsgParams';
-			type: {
-				kind: 'struct';
-				fields: [
-					{
-						name: 'signedMsgOrderUuid';
-						type: {
-							array: ['u8', 8];
-						};
-					},
-					{
-						name: 'maxPosition';
-						type: 'i64';
-					},
-					{
-						name: 'minPosition';
-						type: 'i64';
-					},
-					{
-						name: 'bid';
-						type: 'i64';
+			name: 'initMakerConfig';
+			accounts: [
+				{
+					name: 'user';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'makerConfig';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'marketType';
+					type: {
+						defined: 'MarketType';
+					};
+				},
+				{
+					name: 'marketIndex';
+					type: 'u16';
+				},
+				{
+					name: 'params';
+					type: {
+						defined: 'MakerConfigParams';
+					};
+				}
+			];
+		},
+		{
+			name: 'updateMakerConfig';
+			accounts: [
+				{
+					name: 'user';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'makerConfig';
+					isMut: true;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'params';
+					type: {
+						defined: 'MakerConfigParams';
+					};
+				}
+			];
+		},
+		{
+			name: 'closeMakerConfig';
+			accounts: [
+				{
+					name: 'user';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'makerConfig';
+					isMut: true;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'jitWithConfig';
+			accounts: [
+				{
+					name: 'jit';
+					accounts: [
+						{
+							name: 'state';
+							isMut: false;
+							isSigner: false;
+						},
+						{
+							name: 'user';
+							isMut: true;
+							isSigner: false;
+						},
+						{
+							name: 'userStats';
+							isMut: true;
+							isSigner: false;
+						},
+						{
+							name: 'taker';
+							isMut: true;
+							isSigner: false;
+						},
+						{
+							name: 'takerStats';
+							isMut: true;
+							isSigner: false;
+						},
+						{
+							name: 'authority';
+							isMut: false;
+							isSigner: true;
+						},
+						{
+							name: 'driftProgram';
+							isMut: false;
+							isSigner: false;
+						},
+						{
+							name: 'makerControl';
+							isMut: false;
+							isSigner: false;
+							isOptional: true;
+						},
+						{
+							name: 'riskLedger';
+							isMut: true;
+							isSigner: false;
+						}
+					];
+				},
+				{
+					name: 'makerConfig';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'takerOrderId';
+					type: 'u32';
+				}
+			];
+		},
+		{
+			name: 'initMakerControl';
+			accounts: [
+				{
+					name: 'user';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'makerControl';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'updateMakerControl';
+			accounts: [
+				{
+					name: 'user';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'makerControl';
+					isMut: true;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'params';
+					type: {
+						defined: 'MakerControlParams';
+					};
+				}
+			];
+		},
+		{
+			name: 'initRiskLedger';
+			accounts: [
+				{
+					name: 'user';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'riskLedger';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'params';
+					type: {
+						defined: 'RiskLedgerParams';
+					};
+				}
+			];
+		},
+		{
+			name: 'updateRiskLedger';
+			accounts: [
+				{
+					name: 'user';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'riskLedger';
+					isMut: true;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'params';
+					type: {
+						defined: 'RiskLedgerParams';
+					};
+				}
+			];
+		}
+	];
+	accounts: [
+		{
+			name: 'MakerConfig';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'user';
+						type: 'publicKey';
+					},
+					{
+						name: 'marketType';
+						type: {
+							defined: 'MarketType';
+						};
+					},
+					{
+						name: 'marketIndex';
+						type: 'u16';
+					},
+					{
+						name: 'maxPosition';
+						type: 'i64';
+					},
+					{
+						name: 'minPosition';
+						type: 'i64';
+					},
+					{
+						name: 'bid';
+						type: 'i64';
 					},
 					{
 						name: 'ask';
@@ -269,360 +681,2120 @@ export type JitProxy = {
 								defined: 'PostOnlyParam';
 							};
 						};
+					},
+					{
+						name: 'bump';
+						type: 'u8';
 					}
 				];
 			};
 		},
 		{
-			name: 'PostOnlyParam';
+			name: 'MakerControl';
 			type: {
-				kind: 'enum';
-				variants: [
+				kind: 'struct';
+				fields: [
 					{
-						name: 'None';
+						name: 'user';
+						type: 'publicKey';
 					},
 					{
-						name: 'MustPostOnly';
+						name: 'paused';
+						type: 'bool';
 					},
 					{
-						name: 'TryPostOnly';
+						name: 'pausedPerpMarkets';
+						type: 'u128';
 					},
 					{
-						name: 'Slide';
+						name: 'pausedSpotMarkets';
+						type: 'u128';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
 					}
 				];
 			};
 		},
 		{
-			name: 'PriceType';
+			name: 'RiskLedger';
 			type: {
-				kind: 'enum';
-				variants: [
+				kind: 'struct';
+				fields: [
 					{
-						name: 'Limit';
+						name: 'user';
+						type: 'publicKey';
 					},
 					{
-						name: 'Oracle';
-					}
-				];
-			};
-		},
-		{
-			name: 'MarketType';
-			type: {
-				kind: 'enum';
-				variants: [
+						name: 'windowDuration';
+						type: 'i64';
+					},
 					{
-						name: 'Perp';
+						name: 'maxWindowNotional';
+						type: 'u64';
 					},
 					{
-						name: 'Spot';
+						name: 'maxWindowLoss';
+						type: 'u64';
+					},
+					{
+						name: 'windowStartTs';
+						type: 'i64';
+					},
+					{
+						name: 'windowNotional';
+						type: 'u64';
+					},
+					{
+						name: 'windowPnl';
+						type: 'i64';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
 					}
 				];
 			};
 		}
 	];
-	errors: [
-		{
-			code: 6000;
-			name: 'BidNotCrossed';
-			msg: 'BidNotCrossed';
-		},
-		{
-			code: 6001;
-			name: 'AskNotCrossed';
-			msg: 'AskNotCrossed';
-		},
+	types: [
 		{
-			code: 6002;
-			name: 'TakerOrderNotFound';
-			msg: 'TakerOrderNotFound';
+			name: 'OrderConstraint';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'maxPosition';
+						type: 'i64';
+					},
+					{
+						name: 'minPosition';
+						type: 'i64';
+					},
+					{
+						name: 'marketIndex';
+						type: 'u16';
+					},
+					{
+						name: 'marketType';
+						type: {
+							defined: 'MarketType';
+						};
+					}
+				];
+			};
 		},
 		{
-			code: 6003;
-			name: 'OrderSizeBreached';
-			msg: 'OrderSizeBreached';
+			name: 'JitParams';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'takerOrderId';
+						type: 'u32';
+					},
+					{
+						name: 'maxPosition';
+						type: 'i64';
+					},
+					{
+						name: 'minPosition';
+						type: 'i64';
+					},
+					{
+						name: 'bid';
+						type: 'i64';
+					},
+					{
+						name: 'ask';
+						type: 'i64';
+					},
+					{
+						name: 'priceType';
+						type: {
+							defined: 'PriceType';
+						};
+					},
+					{
+						name: 'postOnly';
+						type: {
+							option: {
+								defined: 'PostOnlyParam';
+							};
+						};
+					},
+					{
+						name: 'maxFillSize';
+						type: {
+							option: 'u64';
+						};
+					},
+					{
+						name: 'minFillSize';
+						type: {
+							option: 'u64';
+						};
+					},
+					{
+						name: 'priceLadder';
+						type: {
+							vec: {
+								defined: 'PriceTier';
+							};
+						};
+					},
+					{
+						name: 'skewPerBase';
+						type: {
+							option: 'i64';
+						};
+					},
+					{
+						name: 'maxOracleConfidenceBps';
+						type: {
+							option: 'u64';
+						};
+					},
+					{
+						name: 'maxOracleDelaySlots';
+						type: {
+							option: 'u64';
+						};
+					},
+					{
+						name: 'validUntilSlot';
+						type: {
+							option: 'u64';
+						};
+					},
+					{
+						name: 'maxTs';
+						type: {
+							option: 'i64';
+						};
+					},
+					{
+						name: 'expectedOraclePrice';
+						type: {
+							option: 'i64';
+						};
+					},
+					{
+						name: 'maxOracleDeviationBps';
+						type: {
+							option: 'u64';
+						};
+					},
+					{
+						name: 'minEdgeBps';
+						type: {
+							option: 'u64';
+						};
+					},
+					{
+						name: 'feeAware';
+						type: 'bool';
+					},
+					{
+						name: 'fillMode';
+						type: {
+							defined: 'FillMode';
+						};
+					},
+					{
+						name: 'reduceOnly';
+						type: 'bool';
+					}
+				];
+			};
 		},
 		{
-			code: 6004;
-			name: 'NoBestBid';
-			msg: 'NoBestBid';
+			name: 'PriceTier';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'sizeThreshold';
+						type: 'u64';
+					},
+					{
+						name: 'bid';
+						type: 'i64';
+					},
+					{
+						name: 'ask';
+						type: 'i64';
+					}
+				];
+			};
 		},
 		{
-			code: 6005;
-			name: 'NoBestAsk';
-			msg: 'NoBestAsk';
+			name: 'JitSignedMsgParams';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'signedMsgOrderUuid';
+						type: {
+							array: ['u8', 8];
+						};
+					},
+					{
+						name: 'maxPosition';
+						type: 'i64';
+					},
+					{
+						name: 'minPosition';
+						type: 'i64';
+					},
+					{
+						name: 'bid';
+						type: 'i64';
+					},
+					{
+						name: 'ask';
+						type: 'i64';
+					},
+					{
+						name: 'priceType';
+						type: {
+							defined: 'PriceType';
+						};
+					},
+					{
+						name: 'postOnly';
+						type: {
+							option: {
+								defined: 'PostOnlyParam';
+							};
+						};
+					},
+					{
+						name: 'maxOracleConfidenceBps';
+						type: {
+							option: 'u64';
+						};
+					},
+					{
+						name: 'maxOracleDelaySlots';
+						type: {
+							option: 'u64';
+						};
+					},
+					{
+						name: 'validUntilSlot';
+						type: {
+							option: 'u64';
+						};
+					},
+					{
+						name: 'maxTs';
+						type: {
+							option: 'i64';
+						};
+					},
+					{
+						name: 'expectedOraclePrice';
+						type: {
+							option: 'i64';
+						};
+					},
+					{
+						name: 'maxOracleDeviationBps';
+						type: {
+							option: 'u64';
+						};
+					},
+					{
+						name: 'reduceOnly';
+						type: 'bool';
+					}
+				];
+			};
 		},
 		{
-			code: 6006;
-			name: 'NoArbOpportunity';
-			msg: 'NoArbOpportunity';
+			name: 'JitFillSummary';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'baseAssetAmountFilled';
+						type: 'u64';
+					},
+					{
+						name: 'fillPrice';
+						type: 'u64';
+					},
+					{
+						name: 'makerPosition';
+						type: 'i64';
+					}
+				];
+			};
 		},
 		{
-			code: 6007;
-			name: 'UnprofitableArb';
-			msg: 'UnprofitableArb';
+			name: 'JitBatchEntry';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'takerIndex';
+						type: 'u8';
+					},
+					{
+						name: 'params';
+						type: {
+							defined: 'JitParams';
+						};
+					},
+					{
+						name: 'extraAccountsIndex';
+						type: 'u8';
+					},
+					{
+						name: 'extraAccountsLen';
+						type: 'u8';
+					}
+				];
+			};
 		},
 		{
-			code: 6008;
-			name: 'PositionLimitBreached';
-			msg: 'PositionLimitBreached';
+			name: 'JitBatchParams';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'entries';
+						type: {
+							vec: {
+								defined: 'JitBatchEntry';
+							};
+						};
+					},
+					{
+						name: 'bestEffort';
+						type: 'bool';
+					}
+				];
+			};
 		},
 		{
-			code: 6009;
-			name: 'NoFill';
-			msg: 'NoFill';
+			name: 'ArbPerpParams';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'minProfitQuote';
+						type: 'i64';
+					},
+					{
+						name: 'maxBaseAssetAmount';
+						type: {
+							option: 'u64';
+						};
+					},
+					{
+						name: 'sizing';
+						type: {
+							defined: 'ArbSizing';
+						};
+					}
+				];
+			};
 		},
 		{
-			code: 6010;
-			name: 'SignedMsgOrderDoesNotExist';
-			msg: 'SignedMsgOrderDoesNotExist';
-		}
-	];
-};
-
-export const IDL: JitProxy = {
-	version: '0.21.0',
-	name: 'jit_proxy',
-	instructions: [
-		{
-			name: 'jit',
-			accounts: [
-				{
-					name: 'state',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'user',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'userStats',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'taker',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'takerStats',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'authority',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [
-				{
-					name: 'params',
-					type: {
-						defined: 'JitParams',
+			name: 'ArbSummary';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'baseAssetAmount';
+						type: 'u64';
 					},
-				},
-			],
+					{
+						name: 'quotePnl';
+						type: 'i64';
+					},
+					{
+						name: 'position';
+						type: 'i64';
+					}
+				];
+			};
 		},
 		{
-			name: 'jitSignedMsg',
-			accounts: [
-				{
-					name: 'state',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'user',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'userStats',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'taker',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'takerStats',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'takerSignedMsgUserOrders',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'authority',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [
-				{
-					name: 'params',
-					type: {
-						defined: 'JitSignedMsgParams',
+			name: 'ArbSpotParams';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'baseTolerance';
+						type: 'u64';
+					}
+				];
+			};
+		},
+		{
+			name: 'ArbBasisParams';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'minSpread';
+						type: 'u64';
 					},
-				},
-			],
+					{
+						name: 'maxBaseAssetAmount';
+						type: 'u64';
+					},
+					{
+						name: 'deltaTolerance';
+						type: 'u64';
+					},
+					{
+						name: 'minProfitQuote';
+						type: 'i64';
+					}
+				];
+			};
 		},
 		{
-			name: 'checkOrderConstraints',
-			accounts: [
-				{
-					name: 'user',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [
-				{
-					name: 'constraints',
-					type: {
-						vec: {
-							defined: 'OrderConstraint',
-						},
+			name: 'MakerConfigParams';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'maxPosition';
+						type: 'i64';
 					},
-				},
-			],
+					{
+						name: 'minPosition';
+						type: 'i64';
+					},
+					{
+						name: 'bid';
+						type: 'i64';
+					},
+					{
+						name: 'ask';
+						type: 'i64';
+					},
+					{
+						name: 'priceType';
+						type: {
+							defined: 'PriceType';
+						};
+					},
+					{
+						name: 'postOnly';
+						type: {
+							option: {
+								defined: 'PostOnlyParam';
+							};
+						};
+					}
+				];
+			};
 		},
 		{
-			name: 'arbPerp',
-			accounts: [
-				{
-					name: 'state',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'user',
-					isMut: true,
-					isSigner: false,
-				},
+			name: 'MakerControlParams';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'paused';
+						type: 'bool';
+					},
+					{
+						name: 'pausedPerpMarkets';
+						type: {
+							vec: 'u16';
+						};
+					},
+					{
+						name: 'pausedSpotMarkets';
+						type: {
+							vec: 'u16';
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'RiskLedgerParams';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'windowDuration';
+						type: 'i64';
+					},
+					{
+						name: 'maxWindowNotional';
+						type: 'u64';
+					},
+					{
+						name: 'maxWindowLoss';
+						type: 'u64';
+					}
+				];
+			};
+		},
+		{
+			name: 'PostOnlyParam';
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'None';
+					},
+					{
+						name: 'MustPostOnly';
+					},
+					{
+						name: 'TryPostOnly';
+					},
+					{
+						name: 'Slide';
+					}
+				];
+			};
+		},
+		{
+			name: 'PriceType';
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'Limit';
+					},
+					{
+						name: 'Oracle';
+					},
+					{
+						name: 'OracleBps';
+					},
+					{
+						name: 'Amm';
+					},
+					{
+						name: 'OracleTwap';
+					}
+				];
+			};
+		},
+		{
+			name: 'MarketType';
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'Perp';
+					},
+					{
+						name: 'Spot';
+					}
+				];
+			};
+		},
+		{
+			name: 'PositionDirection';
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'Long';
+					},
+					{
+						name: 'Short';
+					}
+				];
+			};
+		},
+		{
+			name: 'ArbSizing';
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'QuoteAsset';
+					},
+					{
+						name: 'FreeCollateral';
+					}
+				];
+			};
+		},
+		{
+			name: 'FillMode';
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'Partial';
+					},
+					{
+						name: 'FillOrKill';
+					}
+				];
+			};
+		}
+	];
+	events: [
+		{
+			name: 'JitFillEvent';
+			fields: [
 				{
-					name: 'userStats',
-					isMut: true,
-					isSigner: false,
+					name: 'taker';
+					type: 'publicKey';
+					index: false;
 				},
 				{
-					name: 'authority',
-					isMut: false,
-					isSigner: true,
+					name: 'maker';
+					type: 'publicKey';
+					index: false;
 				},
 				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
+					name: 'marketType';
+					type: {
+						defined: 'MarketType';
+					};
+					index: false;
 				},
-			],
-			args: [
 				{
-					name: 'marketIndex',
-					type: 'u16',
+					name: 'marketIndex';
+					type: 'u16';
+					index: false;
 				},
-			],
+				{
+					name: 'takerDirection';
+					type: {
+						defined: 'PositionDirection';
+					};
+					index: false;
+				},
+				{
+					name: 'takerPrice';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'makerPrice';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'oraclePrice';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'baseAssetAmountFilled';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'slot';
+					type: 'u64';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'JitNoFillEvent';
+			fields: [
+				{
+					name: 'taker';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'maker';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'marketType';
+					type: {
+						defined: 'MarketType';
+					};
+					index: false;
+				},
+				{
+					name: 'marketIndex';
+					type: 'u16';
+					index: false;
+				},
+				{
+					name: 'takerDirection';
+					type: {
+						defined: 'PositionDirection';
+					};
+					index: false;
+				},
+				{
+					name: 'takerPrice';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'makerPrice';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'oraclePrice';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vammBidPrice';
+					type: {
+						option: 'u64';
+					};
+					index: false;
+				},
+				{
+					name: 'vammAskPrice';
+					type: {
+						option: 'u64';
+					};
+					index: false;
+				},
+				{
+					name: 'slot';
+					type: 'u64';
+					index: false;
+				}
+			];
+		}
+	];
+	errors: [
+		{
+			code: 6000;
+			name: 'BidNotCrossed';
+			msg: 'BidNotCrossed';
+		},
+		{
+			code: 6001;
+			name: 'AskNotCrossed';
+			msg: 'AskNotCrossed';
+		},
+		{
+			code: 6002;
+			name: 'TakerOrderNotFound';
+			msg: 'TakerOrderNotFound';
+		},
+		{
+			code: 6003;
+			name: 'OrderSizeBreached';
+			msg: 'OrderSizeBreached';
+		},
+		{
+			code: 6004;
+			name: 'NoBestBid';
+			msg: 'NoBestBid';
+		},
+		{
+			code: 6005;
+			name: 'NoBestAsk';
+			msg: 'NoBestAsk';
+		},
+		{
+			code: 6006;
+			name: 'NoArbOpportunity';
+			msg: 'NoArbOpportunity';
+		},
+		{
+			code: 6007;
+			name: 'UnprofitableArb';
+			msg: 'UnprofitableArb';
+		},
+		{
+			code: 6008;
+			name: 'PositionLimitBreached';
+			msg: 'PositionLimitBreached';
+		},
+		{
+			code: 6009;
+			name: 'NoFill';
+			msg: 'NoFill';
+		},
+		{
+			code: 6010;
+			name: 'SignedMsgOrderDoesNotExist';
+			msg: 'SignedMsgOrderDoesNotExist';
+		},
+		{
+			code: 6011;
+			name: 'DeltaToleranceBreached';
+			msg: 'DeltaToleranceBreached';
+		},
+		{
+			code: 6012;
+			name: 'TakerAccountNotFound';
+			msg: 'TakerAccountNotFound';
+		},
+		{
+			code: 6013;
+			name: 'FillSizeBelowMinimum';
+			msg: 'FillSizeBelowMinimum';
+		},
+		{
+			code: 6014;
+			name: 'PriceTypeNotSupported';
+			msg: 'PriceTypeNotSupported';
+		},
+		{
+			code: 6015;
+			name: 'OracleConfidenceTooWide';
+			msg: 'OracleConfidenceTooWide';
+		},
+		{
+			code: 6016;
+			name: 'OracleTooStale';
+			msg: 'OracleTooStale';
+		},
+		{
+			code: 6017;
+			name: 'Expired';
+			msg: 'Expired';
+		},
+		{
+			code: 6018;
+			name: 'OracleDeviationTooLarge';
+			msg: 'OracleDeviationTooLarge';
+		},
+		{
+			code: 6019;
+			name: 'InsufficientEdge';
+			msg: 'InsufficientEdge';
+		},
+		{
+			code: 6020;
+			name: 'FillOrKillNotFilled';
+			msg: 'FillOrKillNotFilled';
+		},
+		{
+			code: 6021;
+			name: 'ReduceOnlyIncreasesPosition';
+			msg: 'ReduceOnlyIncreasesPosition';
+		},
+		{
+			code: 6022;
+			name: 'InvalidAuthority';
+			msg: 'InvalidAuthority';
+		},
+		{
+			code: 6023;
+			name: 'MakerConfigMarketMismatch';
+			msg: 'MakerConfigMarketMismatch';
+		},
+		{
+			code: 6024;
+			name: 'Paused';
+			msg: 'Paused';
+		},
+		{
+			code: 6025;
+			name: 'WindowNotionalLimitBreached';
+			msg: 'WindowNotionalLimitBreached';
+		},
+		{
+			code: 6026;
+			name: 'WindowLossLimitBreached';
+			msg: 'WindowLossLimitBreached';
+		},
+		{
+			code: 6027;
+			name: 'ExtraAccountsNotFound';
+			msg: 'ExtraAccountsNotFound';
+		},
+		{
+			code: 6028;
+			name: 'InvalidOracleDeviationParams';
+			msg: 'InvalidOracleDeviationParams';
+		},
+		{
+			code: 6029;
+			name: 'MarketIndexNotPausable';
+			msg: 'MarketIndexNotPausable';
+		},
+		{
+			code: 6030;
+			name: 'InvalidWindowDuration';
+			msg: 'InvalidWindowDuration';
+		}
+	];
+};
+
+export const IDL: JitProxy = {
+	version: '0.21.0',
+	name: 'jit_proxy',
+	instructions: [
+		{
+			name: 'jit',
+			accounts: [
+				{
+					name: 'state',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'user',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'userStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'taker',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'takerStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'makerControl',
+					isMut: false,
+					isSigner: false,
+					isOptional: true,
+				},
+				{
+					name: 'riskLedger',
+					isMut: true,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'params',
+					type: {
+						defined: 'JitParams',
+					},
+				},
+			],
+		},
+		{
+			name: 'jitSignedMsg',
+			accounts: [
+				{
+					name: 'state',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'user',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'userStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'taker',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'takerStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'takerSignedMsgUserOrders',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'makerControl',
+					isMut: false,
+					isSigner: false,
+					isOptional: true,
+				},
+				{
+					name: 'riskLedger',
+					isMut: true,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'params',
+					type: {
+						defined: 'JitSignedMsgParams',
+					},
+				},
+			],
+		},
+		{
+			name: 'jitBatch',
+			accounts: [
+				{
+					name: 'state',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'user',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'userStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'makerControl',
+					isMut: false,
+					isSigner: false,
+					isOptional: true,
+				},
+				{
+					name: 'riskLedger',
+					isMut: true,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'params',
+					type: {
+						defined: 'JitBatchParams',
+					},
+				},
+			],
+		},
+		{
+			name: 'checkOrderConstraints',
+			accounts: [
+				{
+					name: 'user',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'constraints',
+					type: {
+						vec: {
+							defined: 'OrderConstraint',
+						},
+					},
+				},
+			],
+		},
+		{
+			name: 'arbPerp',
+			accounts: [
+				{
+					name: 'state',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'user',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'userStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'makerControl',
+					isMut: false,
+					isSigner: false,
+					isOptional: true,
+				},
+				{
+					name: 'riskLedger',
+					isMut: true,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'marketIndex',
+					type: 'u16',
+				},
+				{
+					name: 'params',
+					type: {
+						defined: 'ArbPerpParams',
+					},
+				},
+			],
+		},
+		{
+			name: 'arbSpot',
+			accounts: [
+				{
+					name: 'state',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'user',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'userStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'makerControl',
+					isMut: false,
+					isSigner: false,
+					isOptional: true,
+				},
+				{
+					name: 'riskLedger',
+					isMut: true,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'marketIndex',
+					type: 'u16',
+				},
+				{
+					name: 'params',
+					type: {
+						defined: 'ArbSpotParams',
+					},
+				},
+			],
+		},
+		{
+			name: 'arbBasis',
+			accounts: [
+				{
+					name: 'state',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'user',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'userStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'makerControl',
+					isMut: false,
+					isSigner: false,
+					isOptional: true,
+				},
+				{
+					name: 'riskLedger',
+					isMut: true,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'perpMarketIndex',
+					type: 'u16',
+				},
+				{
+					name: 'spotMarketIndex',
+					type: 'u16',
+				},
+				{
+					name: 'params',
+					type: {
+						defined: 'ArbBasisParams',
+					},
+				},
+			],
+		},
+		{
+			name: 'initMakerConfig',
+			accounts: [
+				{
+					name: 'user',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'makerConfig',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'marketType',
+					type: {
+						defined: 'MarketType',
+					},
+				},
+				{
+					name: 'marketIndex',
+					type: 'u16',
+				},
+				{
+					name: 'params',
+					type: {
+						defined: 'MakerConfigParams',
+					},
+				},
+			],
+		},
+		{
+			name: 'updateMakerConfig',
+			accounts: [
+				{
+					name: 'user',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'makerConfig',
+					isMut: true,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'params',
+					type: {
+						defined: 'MakerConfigParams',
+					},
+				},
+			],
+		},
+		{
+			name: 'closeMakerConfig',
+			accounts: [
+				{
+					name: 'user',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'makerConfig',
+					isMut: true,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'jitWithConfig',
+			accounts: [
+				{
+					name: 'jit',
+					accounts: [
+						{
+							name: 'state',
+							isMut: false,
+							isSigner: false,
+						},
+						{
+							name: 'user',
+							isMut: true,
+							isSigner: false,
+						},
+						{
+							name: 'userStats',
+							isMut: true,
+							isSigner: false,
+						},
+						{
+							name: 'taker',
+							isMut: true,
+							isSigner: false,
+						},
+						{
+							name: 'takerStats',
+							isMut: true,
+							isSigner: false,
+						},
+						{
+							name: 'authority',
+							isMut: false,
+							isSigner: true,
+						},
+						{
+							name: 'driftProgram',
+							isMut: false,
+							isSigner: false,
+						},
+						{
+							name: 'makerControl',
+							isMut: false,
+							isSigner: false,
+							isOptional: true,
+						},
+						{
+							name: 'riskLedger',
+							isMut: true,
+							isSigner: false,
+						},
+					],
+				},
+				{
+					name: 'makerConfig',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'takerOrderId',
+					type: 'u32',
+				},
+			],
+		},
+		{
+			name: 'initMakerControl',
+			accounts: [
+				{
+					name: 'user',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'makerControl',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'updateMakerControl',
+			accounts: [
+				{
+					name: 'user',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'makerControl',
+					isMut: true,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'params',
+					type: {
+						defined: 'MakerControlParams',
+					},
+				},
+			],
+		},
+		{
+			name: 'initRiskLedger',
+			accounts: [
+				{
+					name: 'user',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'riskLedger',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'params',
+					type: {
+						defined: 'RiskLedgerParams',
+					},
+				},
+			],
+		},
+		{
+			name: 'updateRiskLedger',
+			accounts: [
+				{
+					name: 'user',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'riskLedger',
+					isMut: true,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'params',
+					type: {
+						defined: 'RiskLedgerParams',
+					},
+				},
+			],
+		},
+	],
+	accounts: [
+		{
+			name: 'MakerConfig',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'user',
+						type: 'publicKey',
+					},
+					{
+						name: 'marketType',
+						type: {
+							defined: 'MarketType',
+						},
+					},
+					{
+						name: 'marketIndex',
+						type: 'u16',
+					},
+					{
+						name: 'maxPosition',
+						type: 'i64',
+					},
+					{
+						name: 'minPosition',
+						type: 'i64',
+					},
+					{
+						name: 'bid',
+						type: 'i64',
+					},
+					{
+						name: 'ask',
+						type: 'i64',
+					},
+					{
+						name: 'priceType',
+						type: {
+							defined: 'PriceType',
+						},
+					},
+					{
+						name: 'postOnly',
+						type: {
+							option: {
+								defined: 'PostOnlyParam',
+							},
+						},
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+				],
+			},
+		},
+		{
+			name: 'MakerControl',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'user',
+						type: 'publicKey',
+					},
+					{
+						name: 'paused',
+						type: 'bool',
+					},
+					{
+						name: 'pausedPerpMarkets',
+						type: 'u128',
+					},
+					{
+						name: 'pausedSpotMarkets',
+						type: 'u128',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+				],
+			},
+		},
+		{
+			name: 'RiskLedger',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'user',
+						type: 'publicKey',
+					},
+					{
+						name: 'windowDuration',
+						type: 'i64',
+					},
+					{
+						name: 'maxWindowNotional',
+						type: 'u64',
+					},
+					{
+						name: 'maxWindowLoss',
+						type: 'u64',
+					},
+					{
+						name: 'windowStartTs',
+						type: 'i64',
+					},
+					{
+						name: 'windowNotional',
+						type: 'u64',
+					},
+					{
+						name: 'windowPnl',
+						type: 'i64',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+				],
+			},
+		},
+	],
+	types: [
+		{
+			name: 'OrderConstraint',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'maxPosition',
+						type: 'i64',
+					},
+					{
+						name: 'minPosition',
+						type: 'i64',
+					},
+					{
+						name: 'marketIndex',
+						type: 'u16',
+					},
+					{
+						name: 'marketType',
+						type: {
+							defined: 'MarketType',
+						},
+					},
+				],
+			},
+		},
+		{
+			name: 'JitParams',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'takerOrderId',
+						type: 'u32',
+					},
+					{
+						name: 'maxPosition',
+						type: 'i64',
+					},
+					{
+						name: 'minPosition',
+						type: 'i64',
+					},
+					{
+						name: 'bid',
+						type: 'i64',
+					},
+					{
+						name: 'ask',
+						type: 'i64',
+					},
+					{
+						name: 'priceType',
+						type: {
+							defined: 'PriceType',
+						},
+					},
+					{
+						name: 'postOnly',
+						type: {
+							option: {
+								defined: 'PostOnlyParam',
+							},
+						},
+					},
+					{
+						name: 'maxFillSize',
+						type: {
+							option: 'u64',
+						},
+					},
+					{
+						name: 'minFillSize',
+						type: {
+							option: 'u64',
+						},
+					},
+					{
+						name: 'priceLadder',
+						type: {
+							vec: {
+								defined: 'PriceTier',
+							},
+						},
+					},
+					{
+						name: 'skewPerBase',
+						type: {
+							option: 'i64',
+						},
+					},
+					{
+						name: 'maxOracleConfidenceBps',
+						type: {
+							option: 'u64',
+						},
+					},
+					{
+						name: 'maxOracleDelaySlots',
+						type: {
+							option: 'u64',
+						},
+					},
+					{
+						name: 'validUntilSlot',
+						type: {
+							option: 'u64',
+						},
+					},
+					{
+						name: 'maxTs',
+						type: {
+							option: 'i64',
+						},
+					},
+					{
+						name: 'expectedOraclePrice',
+						type: {
+							option: 'i64',
+						},
+					},
+					{
+						name: 'maxOracleDeviationBps',
+						type: {
+							option: 'u64',
+						},
+					},
+					{
+						name: 'minEdgeBps',
+						type: {
+							option: 'u64',
+						},
+					},
+					{
+						name: 'feeAware',
+						type: 'bool',
+					},
+					{
+						name: 'fillMode',
+						type: {
+							defined: 'FillMode',
+						},
+					},
+					{
+						name: 'reduceOnly',
+						type: 'bool',
+					},
+				],
+			},
+		},
+		{
+			name: 'PriceTier',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'sizeThreshold',
+						type: 'u64',
+					},
+					{
+						name: 'bid',
+						type: 'i64',
+					},
+					{
+						name: 'ask',
+						type: 'i64',
+					},
+				],
+			},
+		},
+		{
+			name: 'JitSignedMsgParams',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'signedMsgOrderUuid',
+						type: {
+							array: ['u8', 8],
+						},
+					},
+					{
+						name: 'maxPosition',
+						type: 'i64',
+					},
+					{
+						name: 'minPosition',
+						type: 'i64',
+					},
+					{
+						name: 'bid',
+						type: 'i64',
+					},
+					{
+						name: 'ask',
+						type: 'i64',
+					},
+					{
+						name: 'priceType',
+						type: {
+							defined: 'PriceType',
+						},
+					},
+					{
+						name: 'postOnly',
+						type: {
+							option: {
+								defined: 'PostOnlyParam',
+							},
+						},
+					},
+					{
+						name: 'maxOracleConfidenceBps',
+						type: {
+							option: 'u64',
+						},
+					},
+					{
+						name: 'maxOracleDelaySlots',
+						type: {
+							option: 'u64',
+						},
+					},
+					{
+						name: 'validUntilSlot',
+						type: {
+							option: 'u64',
+						},
+					},
+					{
+						name: 'maxTs',
+						type: {
+							option: 'i64',
+						},
+					},
+					{
+						name: 'expectedOraclePrice',
+						type: {
+							option: 'i64',
+						},
+					},
+					{
+						name: 'maxOracleDeviationBps',
+						type: {
+							option: 'u64',
+						},
+					},
+					{
+						name: 'reduceOnly',
+						type: 'bool',
+					},
+				],
+			},
+		},
+		{
+			name: 'JitFillSummary',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'baseAssetAmountFilled',
+						type: 'u64',
+					},
+					{
+						name: 'fillPrice',
+						type: 'u64',
+					},
+					{
+						name: 'makerPosition',
+						type: 'i64',
+					},
+				],
+			},
+		},
+		{
+			name: 'JitBatchEntry',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'takerIndex',
+						type: 'u8',
+					},
+					{
+						name: 'params',
+						type: {
+							defined: 'JitParams',
+						},
+					},
+					{
+						name: 'extraAccountsIndex',
+						type: 'u8',
+					},
+					{
+						name: 'extraAccountsLen',
+						type: 'u8',
+					},
+				],
+			},
 		},
-	],
-	types: [
 		{
-			name: 'OrderConstraint',
+			name: 'JitBatchParams',
 			type: {
 				kind: 'struct',
 				fields: [
 					{
-						name: 'maxPosition',
-						type: 'i64',
+						name: 'entries',
+						type: {
+							vec: {
+								defined: 'JitBatchEntry',
+							},
+						},
 					},
 					{
-						name: 'minPosition',
+						name: 'bestEffort',
+						type: 'bool',
+					},
+				],
+			},
+		},
+		{
+			name: 'ArbPerpParams',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'minProfitQuote',
 						type: 'i64',
 					},
 					{
-						name: 'marketIndex',
-						type: 'u16',
+						name: 'maxBaseAssetAmount',
+						type: {
+							option: 'u64',
+						},
 					},
 					{
-						name: 'marketType',
+						name: 'sizing',
 						type: {
-							defined: 'MarketType',
+							defined: 'ArbSizing',
 						},
 					},
 				],
 			},
 		},
 		{
-			name: 'JitParams',
+			name: 'ArbSummary',
 			type: {
 				kind: 'struct',
 				fields: [
 					{
-						name: 'takerOrderId',
-						type: 'u32',
+						name: 'baseAssetAmount',
+						type: 'u64',
 					},
 					{
-						name: 'maxPosition',
+						name: 'quotePnl',
 						type: 'i64',
 					},
 					{
-						name: 'minPosition',
+						name: 'position',
 						type: 'i64',
 					},
+				],
+			},
+		},
+		{
+			name: 'ArbSpotParams',
+			type: {
+				kind: 'struct',
+				fields: [
 					{
-						name: 'bid',
-						type: 'i64',
+						name: 'baseTolerance',
+						type: 'u64',
 					},
+				],
+			},
+		},
+		{
+			name: 'ArbBasisParams',
+			type: {
+				kind: 'struct',
+				fields: [
 					{
-						name: 'ask',
-						type: 'i64',
+						name: 'minSpread',
+						type: 'u64',
 					},
 					{
-						name: 'priceType',
-						type: {
-							defined: 'PriceType',
-						},
+						name: 'maxBaseAssetAmount',
+						type: 'u64',
 					},
 					{
-						name: 'postOnly',
-						type: {
-							option: {
-								defined: 'PostOnlyParam',
-							},
-						},
+						name: 'deltaTolerance',
+						type: 'u64',
+					},
+					{
+						name: 'minProfitQuote',
+						type: 'i64',
 					},
 				],
 			},
 		},
 		{
-			name: 'JitSignedMsgParams',
+			name: 'MakerConfigParams',
 			type: {
 				kind: 'struct',
 				fields: [
-					{
-						name: 'signedMsgOrderUuid',
-						type: {
-							array: ['u8', 8],
-						},
-					},
 					{
 						name: 'maxPosition',
 						type: 'i64',
@@ -656,6 +2828,50 @@ export const IDL: JitProxy = {
 				],
 			},
 		},
+		{
+			name: 'MakerControlParams',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'paused',
+						type: 'bool',
+					},
+					{
+						name: 'pausedPerpMarkets',
+						type: {
+							vec: 'u16',
+						},
+					},
+					{
+						name: 'pausedSpotMarkets',
+						type: {
+							vec: 'u16',
+						},
+					},
+				],
+			},
+		},
+		{
+			name: 'RiskLedgerParams',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'windowDuration',
+						type: 'i64',
+					},
+					{
+						name: 'maxWindowNotional',
+						type: 'u64',
+					},
+					{
+						name: 'maxWindowLoss',
+						type: 'u64',
+					},
+				],
+			},
+		},
 		{
 			name: 'PostOnlyParam',
 			type: {
@@ -687,6 +2903,15 @@ export const IDL: JitProxy = {
 					{
 						name: 'Oracle',
 					},
+					{
+						name: 'OracleBps',
+					},
+					{
+						name: 'Amm',
+					},
+					{
+						name: 'OracleTwap',
+					},
 				],
 			},
 		},
@@ -704,6 +2929,177 @@ export const IDL: JitProxy = {
 				],
 			},
 		},
+		{
+			name: 'PositionDirection',
+			type: {
+				kind: 'enum',
+				variants: [
+					{
+						name: 'Long',
+					},
+					{
+						name: 'Short',
+					},
+				],
+			},
+		},
+		{
+			name: 'ArbSizing',
+			type: {
+				kind: 'enum',
+				variants: [
+					{
+						name: 'QuoteAsset',
+					},
+					{
+						name: 'FreeCollateral',
+					},
+				],
+			},
+		},
+		{
+			name: 'FillMode',
+			type: {
+				kind: 'enum',
+				variants: [
+					{
+						name: 'Partial',
+					},
+					{
+						name: 'FillOrKill',
+					},
+				],
+			},
+		},
+	],
+	events: [
+		{
+			name: 'JitFillEvent',
+			fields: [
+				{
+					name: 'taker',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'maker',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'marketType',
+					type: {
+						defined: 'MarketType',
+					},
+					index: false,
+				},
+				{
+					name: 'marketIndex',
+					type: 'u16',
+					index: false,
+				},
+				{
+					name: 'takerDirection',
+					type: {
+						defined: 'PositionDirection',
+					},
+					index: false,
+				},
+				{
+					name: 'takerPrice',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'makerPrice',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'oraclePrice',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'baseAssetAmountFilled',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'slot',
+					type: 'u64',
+					index: false,
+				},
+			],
+		},
+		{
+			name: 'JitNoFillEvent',
+			fields: [
+				{
+					name: 'taker',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'maker',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'marketType',
+					type: {
+						defined: 'MarketType',
+					},
+					index: false,
+				},
+				{
+					name: 'marketIndex',
+					type: 'u16',
+					index: false,
+				},
+				{
+					name: 'takerDirection',
+					type: {
+						defined: 'PositionDirection',
+					},
+					index: false,
+				},
+				{
+					name: 'takerPrice',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'makerPrice',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'oraclePrice',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'vammBidPrice',
+					type: {
+						option: 'u64',
+					},
+					index: false,
+				},
+				{
+					name: 'vammAskPrice',
+					type: {
+						option: 'u64',
+					},
+					index: false,
+				},
+				{
+					name: 'slot',
+					type: 'u64',
+					index: false,
+				},
+			],
+		},
 	],
 	errors: [
 		{
@@ -761,5 +3157,105 @@ export const IDL: JitProxy = {
 			name: 'SignedMsgOrderDoesNotExist',
 			msg: 'SignedMsgOrderDoesNotExist',
 		},
+		{
+			code: 6011,
+			name: 'DeltaToleranceBreached',
+			msg: 'DeltaToleranceBreached',
+		},
+		{
+			code: 6012,
+			name: 'TakerAccountNotFound',
+			msg: 'TakerAccountNotFound',
+		},
+		{
+			code: 6013,
+			name: 'FillSizeBelowMinimum',
+			msg: 'FillSizeBelowMinimum',
+		},
+		{
+			code: 6014,
+			name: 'PriceTypeNotSupported',
+			msg: 'PriceTypeNotSupported',
+		},
+		{
+			code: 6015,
+			name: 'OracleConfidenceTooWide',
+			msg: 'OracleConfidenceTooWide',
+		},
+		{
+			code: 6016,
+			name: 'OracleTooStale',
+			msg: 'OracleTooStale',
+		},
+		{
+			code: 6017,
+			name: 'Expired',
+			msg: 'Expired',
+		},
+		{
+			code: 6018,
+			name: 'OracleDeviationTooLarge',
+			msg: 'OracleDeviationTooLarge',
+		},
+		{
+			code: 6019,
+			name: 'InsufficientEdge',
+			msg: 'InsufficientEdge',
+		},
+		{
+			code: 6020,
+			name: 'FillOrKillNotFilled',
+			msg: 'FillOrKillNotFilled',
+		},
+		{
+			code: 6021,
+			name: 'ReduceOnlyIncreasesPosition',
+			msg: 'ReduceOnlyIncreasesPosition',
+		},
+		{
+			code: 6022,
+			name: 'InvalidAuthority',
+			msg: 'InvalidAuthority',
+		},
+		{
+			code: 6023,
+			name: 'MakerConfigMarketMismatch',
+			msg: 'MakerConfigMarketMismatch',
+		},
+		{
+			code: 6024,
+			name: 'Paused',
+			msg: 'Paused',
+		},
+		{
+			code: 6025,
+			name: 'WindowNotionalLimitBreached',
+			msg: 'WindowNotionalLimitBreached',
+		},
+		{
+			code: 6026,
+			name: 'WindowLossLimitBreached',
+			msg: 'WindowLossLimitBreached',
+		},
+		{
+			code: 6027,
+			name: 'ExtraAccountsNotFound',
+			msg: 'ExtraAccountsNotFound',
+		},
+		{
+			code: 6028,
+			name: 'InvalidOracleDeviationParams',
+			msg: 'InvalidOracleDeviationParams',
+		},
+		{
+			code: 6029,
+			name: 'MarketIndexNotPausable',
+			msg: 'MarketIndexNotPausable',
+		},
+		{
+			code: 6030,
+			name: 'InvalidWindowDuration',
+			msg: 'InvalidWindowDuration',
+		},
 	],
 };